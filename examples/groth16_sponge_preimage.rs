@@ -0,0 +1,56 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Prove with Groth16 the knowledge of a preimage of a sponge hash, using the
+//! R1CS gadgets of the `arkworks` feature:
+//!
+//! ```text
+//! cargo run --release --features arkworks --example groth16_sponge_preimage
+//! ```
+
+use ark_bls12_381::{Bls12_381, Fr};
+use ark_groth16::{
+    create_random_proof, generate_random_parameters, prepare_verifying_key,
+    verify_proof,
+};
+use dusk_plonk::prelude::BlsScalar;
+use reinforced_concrete::hashing::r1cs::{to_fr, SpongePreimageCircuit};
+use reinforced_concrete::hashing::sponge::sponge_zelbet_out_of;
+
+fn main() {
+    // A deterministic rng is enough for the example
+    let rng = &mut ark_std::test_rng();
+    let preimage = vec![BlsScalar::from(3), BlsScalar::from(5)];
+    let hash = sponge_zelbet_out_of(preimage.clone(), 1)[0];
+
+    let params = generate_random_parameters::<Bls12_381, _, _>(
+        SpongePreimageCircuit {
+            length: preimage.len(),
+            preimage: None,
+            hash: BlsScalar::zero(),
+        },
+        rng,
+    )
+    .expect("the circuit is synthesized without a preimage");
+    let proof = create_random_proof(
+        SpongePreimageCircuit {
+            length: preimage.len(),
+            preimage: Some(preimage),
+            hash,
+        },
+        &params,
+        rng,
+    )
+    .expect("the preimage hashes to the public input");
+
+    let pvk = prepare_verifying_key(&params.vk);
+    let valid = verify_proof(&pvk, &proof, &[to_fr(&hash)]).unwrap();
+    let wrong_hash = to_fr(&hash) + Fr::from(1u64);
+    let forged = verify_proof(&pvk, &proof, &[wrong_hash]).unwrap();
+    assert!(valid && !forged);
+
+    println!("proof verified for the hash {:?}", hash);
+}
@@ -0,0 +1,201 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+#
+# Copyright (c) DUSK NETWORK. All rights reserved.
+
+"""Generate the parameters of Reinforced Concrete over the base field of
+Pallas, in src/hashing/halo2/pasta.rs, and the test vector of
+test_pasta_permutation, with an implementation of the permutation that is
+independent from the Rust one.
+
+    python3 scripts/pasta_parameters.py [--search]
+
+The radices are searched for with --search, which takes a few minutes, and are
+otherwise read from RADICES below, which is the result of the search.
+"""
+
+import hashlib
+import math
+import random
+import sys
+import textwrap
+
+p = 0x40000000000000000000000000000000224698FC094CF91B992D30ED00000001
+DIGITS = 27
+
+# Result of search_radices(), from the least significant digit
+V = 647
+RADICES = [
+    682, 671, 676, 659, 686, 705, 660, 689, 690, 683, 712, 687, 674, 678,
+    681, 680, 668, 679, 681, 685, 664, 688, 692, 673, 697, 650, 649,
+]
+
+
+def is_prime(n):
+    return n > 1 and all(n % q for q in range(2, math.isqrt(n) + 1))
+
+
+def is_residue(a):
+    return pow(a % p, (p - 1) // 2, p) == 1
+
+
+def greedy_radices(bound, rng, slack):
+    """Decompose p - 1 from the least significant digit, picking each radix
+    within 2^10 close to the geometric mean of what remains, such that every
+    digit is at least the bound. The last radix is the last digit plus one."""
+    remainder = p - 1
+    radices = []
+    for k in range(DIGITS - 1):
+        left = DIGITS - 1 - k
+        target = math.exp((math.log(remainder) - math.log(bound + slack)) / left)
+        candidates = [
+            s for s in range(bound + 1, 1 << 10) if remainder % s >= bound
+        ]
+        candidates.sort(key=lambda s: abs(s - target) + rng.random() * 6)
+        radices.append(candidates[0])
+        remainder //= candidates[0]
+    if bound <= remainder < (1 << 10) - 1:
+        return radices + [remainder + 1]
+    return None
+
+
+def search_radices():
+    """Largest prime V, from 659 down, for which a decomposition of p - 1 with
+    every digit at least V is found, with the first radices found for it"""
+    for bound in range(659, 560, -1):
+        if not is_prime(bound):
+            continue
+        rng = random.Random(bound)
+        for _ in range(3000):
+            radices = greedy_radices(bound, rng, rng.randrange(5, 200))
+            if radices:
+                return bound, radices
+    raise ValueError("no decomposition found")
+
+
+def decomposition(x, radices):
+    digits = []
+    for s in radices:
+        digits.append(x % s)
+        x //= s
+    return digits
+
+
+def shake(label, length):
+    return hashlib.shake_128(label.encode()).digest(length)
+
+
+def s_box():
+    """Fisher-Yates shuffle of the integers below V, drawing 16 bit little
+    endian words with rejection"""
+    stream = shake("ReinforcedConcrete Pasta S-box", 1 << 16)
+    words = (
+        int.from_bytes(stream[i : i + 2], "little")
+        for i in range(0, len(stream), 2)
+    )
+    table = list(range(V))
+    for i in range(V - 1, 0, -1):
+        limit = (65536 // (i + 1)) * (i + 1)
+        word = next(w for w in words if w < limit)
+        j = word % (i + 1)
+        table[i], table[j] = table[j], table[i]
+    return table
+
+
+def round_constants():
+    """Successive 32 byte little endian words with the top bit cleared, that
+    are smaller than p"""
+    stream = shake("ReinforcedConcrete Pasta round constants", 1 << 16)
+    constants = []
+    for i in range(0, len(stream), 32):
+        word = int.from_bytes(stream[i : i + 32], "little") & ((1 << 255) - 1)
+        if word < p:
+            constants.append(word)
+        if len(constants) == 18:
+            return [constants[3 * r : 3 * r + 3] for r in range(6)]
+    raise ValueError("stream too short")
+
+
+def permutation(x, table, constants):
+    def concrete(x, r):
+        total = sum(x)
+        return [(total + x[i] + constants[r][i]) % p for i in range(3)]
+
+    def brick(x):
+        return [
+            pow(x[0], 5, p),
+            x[1] * (x[0] * x[0] + x[0] + 2) % p,
+            x[2] * (x[1] * x[1] + 3 * x[1] + 4) % p,
+        ]
+
+    def bar(x):
+        digits = decomposition(x, RADICES)
+        images = [table[d] if d < V else d for d in digits]
+        acc = 0
+        for k in range(DIGITS - 1, -1, -1):
+            acc = acc * RADICES[k] + images[k]
+        return acc % p
+
+    for r in range(3):
+        x = brick(concrete(x, r))
+    x = [bar(a) for a in concrete(x, 3)]
+    # The same order of the round constants as zelbet_out_of_circuit
+    for r in [4, 5, 4]:
+        x = brick(concrete(x, r))
+    return concrete(x, 3)
+
+
+def limbs(x):
+    return [(x >> (64 * i)) & ((1 << 64) - 1) for i in range(4)]
+
+
+def rust_array(values):
+    body = ", ".join(map(str, values))
+    return "\n".join(
+        textwrap.wrap(body, 76, initial_indent="    ", subsequent_indent="    ")
+    )
+
+
+def main():
+    global V, RADICES
+    if "--search" in sys.argv[1:]:
+        V, RADICES = search_radices()
+
+    # The brick layer is a permutation: 5 is the smallest exponent coprime to
+    # p - 1, and both quadratics have no roots
+    assert next(d for d in range(2, p) if math.gcd(d, p - 1) == 1) == 5
+    assert not is_residue(1 - 4 * 2) and not is_residue(9 - 4 * 4)
+
+    modulus_digits = decomposition(p - 1, RADICES)
+    assert math.prod(RADICES) > p
+    assert all(V < s <= 1 << 10 for s in RADICES)
+    assert all(v >= V for v in modulus_digits)
+    table = s_box()
+    assert sorted(table) == list(range(V))
+    constants = round_constants()
+
+    print("pub const V: u64 = %d;" % V)
+    print("const RADICES: [u64; 27] = [\n%s,\n];" % rust_array(RADICES))
+    print(
+        "const MODULUS_DIGITS: [u64; 27] = [\n%s,\n];"
+        % rust_array(modulus_digits)
+    )
+    print("const S_BOX: [u64; %d] = [\n%s,\n];" % (V, rust_array(table)))
+    print("const ROUND_CONSTANTS: [[[u64; 4]; 3]; 6] = [")
+    for r in range(6):
+        print("    [")
+        for c in constants[r]:
+            print("        [")
+            print("\n".join("            0x%016x," % l for l in limbs(c)))
+            print("        ],")
+        print("    ],")
+    print("];")
+
+    print("\n// test_pasta_permutation: permutation([0, 1, 2])")
+    for output in permutation([0, 1, 2], table, constants):
+        print("// " + ", ".join("0x%016x" % l for l in limbs(output)))
+
+
+if __name__ == "__main__":
+    main()
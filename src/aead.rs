@@ -0,0 +1,440 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Authenticated encryption of vectors of BLS scalars with the Zelbet duplex.
+//!
+//! The state is keyed as `[key, nonce, domain]`, where the domain binds the
+//! length of the message, and permuted. Each block of two message elements is
+//! then encrypted by adding the rate of the state to it, the ciphertext
+//! overwrites the rate, and the state is permuted again. The tag is the first
+//! element of the final state, and is appended to the ciphertext.
+
+use crate::commitment::commitment_gadget;
+use crate::hashing::sponge::domain_tag;
+use crate::hashing::zelbet::{zelbet_out_of_circuit, ZelbetConstants};
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Label of the duplex domain used for encryption
+pub const AEAD_DOMAIN: &[u8] = b"rc.aead";
+
+/// Domain of the duplex for a message of the given length, which is the tag of
+/// [`AEAD_DOMAIN`] followed by the length as eight little endian bytes, so that
+/// distinct lengths give distinct domains.
+pub fn aead_domain(message_len: usize) -> BlsScalar {
+    let mut label = AEAD_DOMAIN.to_vec();
+    label.extend_from_slice(&(message_len as u64).to_le_bytes());
+    domain_tag(&label)
+}
+
+/// Encrypt a message under the key and nonce (out of circuit), returning the
+/// ciphertext followed by the tag. A nonce must never be reused with the same
+/// key.
+pub fn encrypt(
+    key: BlsScalar,
+    nonce: BlsScalar,
+    message: &[BlsScalar],
+) -> Vec<BlsScalar> {
+    let mut state =
+        zelbet_out_of_circuit([key, nonce, aead_domain(message.len())]);
+    let mut ciphertext = Vec::with_capacity(message.len() + 1);
+
+    message.chunks(2).for_each(|block| {
+        block.iter().enumerate().for_each(|(i, m)| {
+            state[i] += m;
+            ciphertext.push(state[i]);
+        });
+        state = zelbet_out_of_circuit(state);
+    });
+    ciphertext.push(state[0]);
+
+    ciphertext
+}
+
+/// Run the duplex over a ciphertext without its tag, returning the message
+/// and the tag it should be followed by
+fn decrypt_blocks(
+    key: BlsScalar,
+    nonce: BlsScalar,
+    ciphertext: &[BlsScalar],
+) -> (Vec<BlsScalar>, BlsScalar) {
+    let mut state =
+        zelbet_out_of_circuit([key, nonce, aead_domain(ciphertext.len())]);
+    let mut message = Vec::with_capacity(ciphertext.len());
+
+    ciphertext.chunks(2).for_each(|block| {
+        block.iter().enumerate().for_each(|(i, c)| {
+            message.push(c - state[i]);
+            state[i] = *c;
+        });
+        state = zelbet_out_of_circuit(state);
+    });
+
+    (message, state[0])
+}
+
+/// Decrypt a ciphertext followed by its tag (out of circuit), returning `None`
+/// if the tag does not authenticate the ciphertext under the key and nonce
+pub fn decrypt(
+    key: BlsScalar,
+    nonce: BlsScalar,
+    ciphertext: &[BlsScalar],
+) -> Option<Vec<BlsScalar>> {
+    let (tag, ciphertext) = ciphertext.split_last()?;
+    let (message, computed_tag) = decrypt_blocks(key, nonce, ciphertext);
+
+    match computed_tag == *tag {
+        true => Some(message),
+        false => None,
+    }
+}
+
+/// Decrypt a ciphertext followed by its tag in circuit, constraining the tag
+/// and returning the variables of the message. The key, the nonce and the
+/// ciphertext are given as variables along with their values, and the length
+/// of the ciphertext is part of the circuit description. The ciphertext of an
+/// empty message is only checked against its tag, and a ciphertext without a
+/// tag makes the circuit unsatisfiable, as it is rejected by [`decrypt`].
+#[allow(clippy::too_many_arguments)]
+pub fn decrypt_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    key: Variable,
+    key_value: BlsScalar,
+    nonce: Variable,
+    nonce_value: BlsScalar,
+    ciphertext: &[Variable],
+    ciphertext_values: &[BlsScalar],
+) -> Vec<Variable> {
+    assert_eq!(ciphertext.len(), ciphertext_values.len());
+    let (tag, ciphertext) = match ciphertext.split_last() {
+        Some(split) => split,
+        None => {
+            let zero =
+                composer.add_witness_to_circuit_description(BlsScalar::zero());
+            composer.constrain_to_constant(zero, BlsScalar::one(), None);
+            return vec![];
+        }
+    };
+    let domain_value = aead_domain(ciphertext.len());
+    let domain = composer.add_witness_to_circuit_description(domain_value);
+    let mut state_values = [key_value, nonce_value, domain_value];
+    let mut state =
+        constants.permute(composer, &[key, nonce, domain], &state_values);
+    state_values = zelbet_out_of_circuit(state_values);
+    let mut message = Vec::with_capacity(ciphertext.len());
+
+    ciphertext
+        .chunks(2)
+        .zip(ciphertext_values.chunks(2))
+        .for_each(|(block, block_values)| {
+            block.iter().enumerate().for_each(|(i, c)| {
+                message.push(composer.big_add(
+                    (BlsScalar::one(), *c),
+                    (-BlsScalar::one(), state[i]),
+                    None,
+                    BlsScalar::zero(),
+                    None,
+                ));
+                state[i] = *c;
+                state_values[i] = block_values[i];
+            });
+            state = constants.permute(composer, &state, &state_values);
+            state_values = zelbet_out_of_circuit(state_values);
+        });
+    composer.assert_equal(state[0], *tag);
+
+    message
+}
+
+/// Prove in circuit that a ciphertext, given as public inputs, decrypts under
+/// the key and nonce to a message that opens the commitment, also a public
+/// input. The commitment is computed with
+/// [`commit`](crate::commitment::commit). The key, the nonce and the blinding
+/// are given as variables along with their values.
+#[allow(clippy::too_many_arguments)]
+pub fn verifiable_decryption_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    key: Variable,
+    key_value: BlsScalar,
+    nonce: Variable,
+    nonce_value: BlsScalar,
+    blinding: Variable,
+    blinding_value: BlsScalar,
+    ciphertext: &[BlsScalar],
+    commitment: BlsScalar,
+) -> Vec<Variable> {
+    let ciphertext_values = ciphertext;
+    let ciphertext: Vec<Variable> = ciphertext_values
+        .iter()
+        .map(|c| {
+            let var = composer.add_input(*c);
+            composer.constrain_to_constant(var, BlsScalar::zero(), Some(-c));
+            var
+        })
+        .collect();
+
+    let message = decrypt_gadget(
+        composer,
+        constants,
+        key,
+        key_value,
+        nonce,
+        nonce_value,
+        &ciphertext,
+        ciphertext_values,
+    );
+    let (message_values, _) = decrypt_blocks(
+        key_value,
+        nonce_value,
+        &ciphertext_values[..ciphertext_values.len().saturating_sub(1)],
+    );
+    let computed = commitment_gadget(
+        composer,
+        constants,
+        &message,
+        &message_values,
+        blinding,
+        blinding_value,
+    );
+    composer.constrain_to_constant(
+        computed,
+        BlsScalar::zero(),
+        Some(-commitment),
+    );
+
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commitment::commit;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+
+    #[test]
+    fn test_aead_vectors() {
+        let message =
+            [BlsScalar::from(1), BlsScalar::from(2), BlsScalar::from(3)];
+        assert_eq!(
+            encrypt(BlsScalar::from(3), BlsScalar::from(5), &message),
+            vec![
+                BlsScalar([
+                    5554525318067382496,
+                    15253049337473289259,
+                    13589856226516239654,
+                    7076562549407623080,
+                ]),
+                BlsScalar([
+                    8929733116618690388,
+                    10331021881766212169,
+                    16755074158638837026,
+                    7006277140164095340,
+                ]),
+                BlsScalar([
+                    8884925394731747847,
+                    15931586730981573520,
+                    282024003938667806,
+                    73019555573246467,
+                ]),
+                BlsScalar([
+                    3393071336400501586,
+                    11461900086066894045,
+                    13199557693454158535,
+                    2822036583812570258,
+                ]),
+            ]
+        );
+        assert_eq!(
+            encrypt(BlsScalar::from(3), BlsScalar::from(5), &[]),
+            vec![BlsScalar([
+                1000876961418380893,
+                3641102019351104769,
+                11692196156370000546,
+                7453900651524158556,
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_aead_round_trip_and_tampering() {
+        let key = BlsScalar::from(3);
+        let nonce = BlsScalar::from(5);
+        (0..5).for_each(|message_len| {
+            let message: Vec<BlsScalar> =
+                (0..message_len).map(|m| BlsScalar::from(m + 10)).collect();
+            let ciphertext = encrypt(key, nonce, &message);
+            assert_eq!(ciphertext.len(), message_len as usize + 1);
+            assert_eq!(decrypt(key, nonce, &ciphertext), Some(message));
+
+            // Any change to the ciphertext, tag, key or nonce is detected
+            (0..ciphertext.len()).for_each(|i| {
+                let mut tampered = ciphertext.clone();
+                tampered[i] += BlsScalar::one();
+                assert_eq!(decrypt(key, nonce, &tampered), None);
+            });
+            assert_eq!(
+                decrypt(key + BlsScalar::one(), nonce, &ciphertext),
+                None
+            );
+            assert_eq!(
+                decrypt(key, nonce + BlsScalar::one(), &ciphertext),
+                None
+            );
+        });
+
+        // Truncated ciphertexts are rejected
+        let ciphertext = encrypt(key, nonce, &[BlsScalar::one(); 3]);
+        assert_eq!(decrypt(key, nonce, &ciphertext[1..]), None);
+        assert_eq!(decrypt(key, nonce, &[]), None);
+    }
+
+    fn decrypt_circuit(
+        composer: &mut StandardComposer,
+        ciphertext_values: &[BlsScalar],
+    ) {
+        let hash_table = bar_lookup_table();
+        composer.append_lookup_table(&hash_table);
+        let constants = ZelbetConstants::new(composer);
+
+        let key = composer.add_input(BlsScalar::from(3));
+        let nonce = composer.add_input(BlsScalar::from(5));
+        let ciphertext: Vec<Variable> = ciphertext_values
+            .iter()
+            .map(|c| composer.add_input(*c))
+            .collect();
+        decrypt_gadget(
+            composer,
+            &constants,
+            key,
+            BlsScalar::from(3),
+            nonce,
+            BlsScalar::from(5),
+            &ciphertext,
+            ciphertext_values,
+        );
+
+        pad_lookup_gates(composer);
+    }
+
+    #[test]
+    fn test_decrypt_gadget_empty_message() {
+        let res = gadget_tester(
+            |composer| {
+                let ciphertext =
+                    encrypt(BlsScalar::from(3), BlsScalar::from(5), &[]);
+                assert_eq!(ciphertext.len(), 1);
+                decrypt_circuit(composer, &ciphertext);
+            },
+            4000,
+        );
+        assert!(res.is_ok());
+
+        // The tag of an empty message is still checked
+        let res = gadget_tester(
+            |composer| {
+                let ciphertext =
+                    encrypt(BlsScalar::from(3), BlsScalar::from(5), &[]);
+                decrypt_circuit(composer, &[ciphertext[0] + BlsScalar::one()]);
+            },
+            4000,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_decrypt_gadget_without_tag_fails() {
+        let res =
+            gadget_tester(|composer| decrypt_circuit(composer, &[]), 4000);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_verifiable_decryption_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let message = [
+                    BlsScalar::from(1),
+                    BlsScalar::from(2),
+                    BlsScalar::from(3),
+                ];
+                let ciphertext =
+                    encrypt(BlsScalar::from(3), BlsScalar::from(5), &message);
+
+                let key = composer.add_input(BlsScalar::from(3));
+                let nonce = composer.add_input(BlsScalar::from(5));
+                let blinding = composer.add_input(BlsScalar::from(42));
+                verifiable_decryption_gadget(
+                    composer,
+                    &constants,
+                    key,
+                    BlsScalar::from(3),
+                    nonce,
+                    BlsScalar::from(5),
+                    blinding,
+                    BlsScalar::from(42),
+                    &ciphertext,
+                    commit(&message, BlsScalar::from(42)),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            9000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_verifiable_decryption_gadget_wrong_plaintext_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let message = [
+                    BlsScalar::from(1),
+                    BlsScalar::from(2),
+                    BlsScalar::from(3),
+                ];
+                let ciphertext =
+                    encrypt(BlsScalar::from(3), BlsScalar::from(5), &message);
+
+                // The commitment is to a message other than the encrypted one
+                let key = composer.add_input(BlsScalar::from(3));
+                let nonce = composer.add_input(BlsScalar::from(5));
+                let blinding = composer.add_input(BlsScalar::from(42));
+                verifiable_decryption_gadget(
+                    composer,
+                    &constants,
+                    key,
+                    BlsScalar::from(3),
+                    nonce,
+                    BlsScalar::from(5),
+                    blinding,
+                    BlsScalar::from(42),
+                    &ciphertext,
+                    commit(
+                        &[BlsScalar::from(1), BlsScalar::from(2)],
+                        BlsScalar::from(42),
+                    ),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            9000,
+        );
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,70 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Print the cost of the Zelbet circuits per component, as a table or as
+//! JSON with `--json`:
+//!
+//! ```text
+//! cost_report [--json] [permutation | compression | sponge <n> <m>]...
+//! ```
+//!
+//! Without a configuration, the permutation, the compression and the sponge
+//! with two inputs and one output are reported.
+
+use reinforced_concrete::hashing::cost::CostReport;
+use std::env;
+use std::process;
+
+const USAGE: &str =
+    "usage: cost_report [--json] [permutation | compression | sponge <n> <m>]...";
+
+fn parse_length(argument: Option<String>) -> usize {
+    match argument.map(|a| a.parse::<usize>()) {
+        Some(Ok(length)) if length > 0 => length,
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let mut json = false;
+    let mut reports = Vec::new();
+    let mut arguments = env::args().skip(1);
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--json" => json = true,
+            "permutation" => reports.push(CostReport::permutation()),
+            "compression" => reports.push(CostReport::compression()),
+            "sponge" => {
+                let length_in = parse_length(arguments.next());
+                let length_out = parse_length(arguments.next());
+                reports.push(CostReport::sponge(length_in, length_out));
+            }
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(1);
+            }
+        }
+    }
+    if reports.is_empty() {
+        reports = vec![
+            CostReport::permutation(),
+            CostReport::compression(),
+            CostReport::sponge(2, 1),
+        ];
+    }
+
+    match json {
+        true => {
+            let reports: Vec<String> =
+                reports.iter().map(CostReport::to_json).collect();
+            println!("[{}]", reports.join(", "));
+        }
+        false => reports.iter().for_each(|report| println!("{}", report)),
+    }
+}
@@ -0,0 +1,270 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Hiding commitments to vectors of BLS scalars, computed with the domain
+//! separated Zelbet sponge. The input to the sponge is the
+//! [`length_prefixed_input`] `[blinding, message length, message...]`.
+
+use crate::hashing::sponge::{
+    domain_tag, length_prefixed_input, length_prefixed_input_gadget,
+    sponge_zelbet_domain_gadget, sponge_zelbet_domain_out_of,
+};
+use crate::hashing::zelbet::ZelbetConstants;
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Label of the sponge domain used for commitments
+pub const COMMITMENT_DOMAIN: &[u8] = b"rc.commitment";
+
+/// Commit to a message with the given blinding factor (out of circuit). The
+/// blinding factor should be sampled uniformly at random for the commitment to
+/// be hiding.
+pub fn commit(message: &[BlsScalar], blinding: BlsScalar) -> BlsScalar {
+    let input = length_prefixed_input(blinding, message);
+    sponge_zelbet_domain_out_of(domain_tag(COMMITMENT_DOMAIN), input, 1)[0]
+}
+
+/// Check that a message and blinding factor open the commitment
+pub fn verify_opening(
+    commitment: BlsScalar,
+    message: &[BlsScalar],
+    blinding: BlsScalar,
+) -> bool {
+    commit(message, blinding) == commitment
+}
+
+/// Compute the commitment to a message in circuit, where the message and the
+/// blinding factor are given as variables along with their values.
+pub fn commitment_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    message: &[Variable],
+    message_values: &[BlsScalar],
+    blinding: Variable,
+    blinding_value: BlsScalar,
+) -> Variable {
+    let (input, input_values) = length_prefixed_input_gadget(
+        composer,
+        blinding,
+        blinding_value,
+        message,
+        message_values,
+    );
+    sponge_zelbet_domain_gadget(
+        composer,
+        constants,
+        domain_tag(COMMITMENT_DOMAIN),
+        input,
+        &input_values,
+        1,
+    )[0]
+}
+
+/// Prove in circuit the knowledge of a message and blinding factor opening the
+/// commitment, which is a public input of the circuit. The message and the
+/// blinding factor are given along with their values.
+pub fn commitment_opening_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    message: &[Variable],
+    message_values: &[BlsScalar],
+    blinding: Variable,
+    blinding_value: BlsScalar,
+    commitment: BlsScalar,
+) {
+    let computed = commitment_gadget(
+        composer,
+        constants,
+        message,
+        message_values,
+        blinding,
+        blinding_value,
+    );
+    composer.constrain_to_constant(
+        computed,
+        BlsScalar::zero(),
+        Some(-commitment),
+    );
+}
+
+/// Number of gates added by [`commitment_opening_gadget`] for a message of the
+/// given length, not counting the [`ZelbetConstants`] that are shared with the
+/// rest of the circuit
+pub fn commitment_opening_cost(message_len: usize) -> usize {
+    let mut composer = StandardComposer::new();
+    let constants = ZelbetConstants::new(&mut composer);
+    let blinding = composer.add_input(BlsScalar::zero());
+    let message = vec![composer.add_input(BlsScalar::zero()); message_len];
+
+    let size_before = composer.circuit_size();
+    commitment_opening_gadget(
+        &mut composer,
+        &constants,
+        &message,
+        &vec![BlsScalar::zero(); message_len],
+        blinding,
+        BlsScalar::zero(),
+        BlsScalar::zero(),
+    );
+
+    composer.circuit_size() - size_before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+
+    #[test]
+    fn test_commitment_vectors() {
+        let message =
+            [BlsScalar::from(1), BlsScalar::from(2), BlsScalar::from(3)];
+        assert_eq!(
+            commit(&message, BlsScalar::from(42)),
+            BlsScalar([
+                13102061481518284458,
+                2230570736780213549,
+                4836443123911231392,
+                3674359129505340907,
+            ])
+        );
+        assert_eq!(
+            commit(&[], BlsScalar::from(7)),
+            BlsScalar([
+                16440046797727802882,
+                12844921878199770954,
+                10545023519205603704,
+                1385162627648973920,
+            ])
+        );
+        assert_eq!(
+            commit(&[-BlsScalar::one()], BlsScalar::from(5)),
+            BlsScalar([
+                8303135047633306314,
+                16470834069962054006,
+                16547480911573225117,
+                3202382649608828231,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_commitment_binding_and_separation() {
+        let message = [BlsScalar::from(1), BlsScalar::from(2)];
+        let blinding = BlsScalar::from(42);
+        let commitment = commit(&message, blinding);
+        assert!(verify_opening(commitment, &message, blinding));
+        assert!(!verify_opening(commitment, &message, BlsScalar::from(43)));
+        assert!(!verify_opening(
+            commitment,
+            &[BlsScalar::from(2), BlsScalar::from(1)],
+            blinding
+        ));
+
+        // A message ending in the padding of the sponge does not collide
+        assert_ne!(
+            commit(&[BlsScalar::from(1)], blinding),
+            commit(&[BlsScalar::from(1), BlsScalar::one()], blinding)
+        );
+        // The commitment is separated from the plain sponge on the same input
+        assert_ne!(
+            commitment,
+            crate::hashing::sponge::sponge_zelbet_out_of(
+                vec![blinding, BlsScalar::from(2), message[0], message[1]],
+                1
+            )[0]
+        );
+    }
+
+    #[test]
+    fn test_commitment_opening_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let message_scalars = [
+                    BlsScalar::from(1),
+                    BlsScalar::from(2),
+                    BlsScalar::from(3),
+                ];
+                let commitment = commit(&message_scalars, BlsScalar::from(42));
+
+                let message: Vec<Variable> = message_scalars
+                    .iter()
+                    .map(|m| composer.add_input(*m))
+                    .collect();
+                let blinding = composer.add_input(BlsScalar::from(42));
+                commitment_opening_gadget(
+                    composer,
+                    &constants,
+                    &message,
+                    &message_scalars,
+                    blinding,
+                    BlsScalar::from(42),
+                    commitment,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            5000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_commitment_opening_gadget_wrong_blinding_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let message_scalars = [
+                    BlsScalar::from(1),
+                    BlsScalar::from(2),
+                    BlsScalar::from(3),
+                ];
+                let commitment = commit(&message_scalars, BlsScalar::from(42));
+
+                let message: Vec<Variable> = message_scalars
+                    .iter()
+                    .map(|m| composer.add_input(*m))
+                    .collect();
+                let blinding = composer.add_input(BlsScalar::from(41));
+                commitment_opening_gadget(
+                    composer,
+                    &constants,
+                    &message,
+                    &message_scalars,
+                    blinding,
+                    BlsScalar::from(41),
+                    commitment,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            5000,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_commitment_opening_cost() {
+        // The blinding factor and length fill the first block of the sponge,
+        // and every two further elements cost one more permutation
+        assert_eq!(commitment_opening_cost(1), commitment_opening_cost(2));
+        assert_eq!(commitment_opening_cost(3), commitment_opening_cost(4));
+        assert_eq!(
+            commitment_opening_cost(3) - commitment_opening_cost(1),
+            commitment_opening_cost(1) - commitment_opening_cost(0)
+        );
+    }
+}
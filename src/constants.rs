@@ -5313,6 +5313,4230 @@ pub const SBOX_U256: [u256; 659] = [
     u256([346, 0, 0, 0]),
 ];
 
+/// Coefficients of the polynomial of degree 702, from the constant one, that
+/// is equal to the S-box on the digits smaller than 659 and to the identity on
+/// the larger digits up to the largest radix 703, in Montgomery form
+pub const SBOX_POLYNOMIAL: [Scalar; 703] = [
+    Scalar([
+        141733920735,
+        3786924329048351777,
+        7316984860854396752,
+        1035104743316445617,
+    ]),
+    Scalar([
+        863792548790754317,
+        3602919058787626886,
+        6980302039173881156,
+        7430455927556974186,
+    ]),
+    Scalar([
+        17979990517033305559,
+        1717952366929101260,
+        7527213273745908646,
+        908251577271126487,
+    ]),
+    Scalar([
+        7154727741345440959,
+        12848664586243952671,
+        2619424051692854048,
+        827767748481252897,
+    ]),
+    Scalar([
+        1576361193709697076,
+        4433982818230899196,
+        1800215144363330650,
+        1653324839198029915,
+    ]),
+    Scalar([
+        6369521806297053458,
+        10942331978531408281,
+        6978610087623443803,
+        2569121793283446863,
+    ]),
+    Scalar([
+        7759501669699512746,
+        15696608340706762119,
+        5346387364587340305,
+        566967371341907413,
+    ]),
+    Scalar([
+        5202604037302482618,
+        9945146362638790186,
+        14477509024221061947,
+        2482569483760342882,
+    ]),
+    Scalar([
+        1358961776195165181,
+        14679626746833645764,
+        4980277486034503430,
+        173589371612488679,
+    ]),
+    Scalar([
+        15324739226575573626,
+        7595903028022901494,
+        12216139437248848291,
+        2114061627678156509,
+    ]),
+    Scalar([
+        6469780012156903922,
+        8780258406540781484,
+        1727905115150642425,
+        7665147535140646682,
+    ]),
+    Scalar([
+        12650715293973702195,
+        9097974139660691251,
+        1972403103509487989,
+        6463745829032499796,
+    ]),
+    Scalar([
+        120808303957898545,
+        1903168996798309305,
+        10423407028605605317,
+        1106051328911745375,
+    ]),
+    Scalar([
+        14651583188817398654,
+        1208368319736096874,
+        13124852446760960002,
+        3752450219582279555,
+    ]),
+    Scalar([
+        12677971518773509773,
+        15401107253369546065,
+        11008366069337015491,
+        3367590525197368031,
+    ]),
+    Scalar([
+        12210216918294422468,
+        12765403849523340097,
+        16087093267474761377,
+        3319327413238782900,
+    ]),
+    Scalar([
+        415688447197493483,
+        4608823349540295741,
+        11051341556717219633,
+        3610678933633207817,
+    ]),
+    Scalar([
+        18342997132267900046,
+        256152603098597629,
+        3923789468132278240,
+        306798743778386592,
+    ]),
+    Scalar([
+        2016444522040814124,
+        3306544327362314367,
+        11147446937781031608,
+        1235742497687899360,
+    ]),
+    Scalar([
+        16746619386336966054,
+        11754769076960616796,
+        13297417431172478380,
+        5809656607531776689,
+    ]),
+    Scalar([
+        11229720029816151794,
+        6190597737581496870,
+        1421308222031905626,
+        2949792408790377348,
+    ]),
+    Scalar([
+        6128247170368460971,
+        11641125976870765241,
+        12159495188438335374,
+        8276391271922376093,
+    ]),
+    Scalar([
+        18035771292627009544,
+        8890635040391274953,
+        16536763562473464311,
+        3236748876330921754,
+    ]),
+    Scalar([
+        5186168855029609800,
+        17573603758185613388,
+        17458948755421960419,
+        2437353064264731561,
+    ]),
+    Scalar([
+        7066215209792312871,
+        4534103196727761361,
+        5797786686216590526,
+        3797484683663579425,
+    ]),
+    Scalar([
+        15353549981761349309,
+        10563065494636458600,
+        5906627574851641798,
+        3653700866468840528,
+    ]),
+    Scalar([
+        9776669473569516258,
+        6201276511480275210,
+        8293125576550296476,
+        6243045062124531099,
+    ]),
+    Scalar([
+        17896079255916649776,
+        1145438760106666694,
+        15866498645610233038,
+        290434537579986061,
+    ]),
+    Scalar([
+        1157648955032312647,
+        11013421643220168812,
+        10223056377210524643,
+        2450123889598226212,
+    ]),
+    Scalar([
+        3139285954536070195,
+        16864487294723308604,
+        808687351229348561,
+        195796669205910864,
+    ]),
+    Scalar([
+        1184169243256608857,
+        6466688724353561854,
+        8184448658525439216,
+        3459075995926010684,
+    ]),
+    Scalar([
+        16063146206849365028,
+        10480290491656552373,
+        1875813318822479053,
+        7286205148178750366,
+    ]),
+    Scalar([
+        13357667428987067646,
+        16286727931405168878,
+        12073216361282008185,
+        2701534259857028588,
+    ]),
+    Scalar([
+        4152127791200912345,
+        10017002355089119553,
+        10967662865652783238,
+        787856935429088716,
+    ]),
+    Scalar([
+        3116786152629542035,
+        11817375776720088768,
+        1401057071428900604,
+        3395425588877415435,
+    ]),
+    Scalar([
+        6529167379848099934,
+        13935569314890258282,
+        7838839782267903564,
+        1191950726063872615,
+    ]),
+    Scalar([
+        7328174067331205345,
+        13782545202769391487,
+        9069223179409157917,
+        1507430827924675310,
+    ]),
+    Scalar([
+        12769217457630146706,
+        4909810467470181449,
+        5424253350924182292,
+        8352408396179067712,
+    ]),
+    Scalar([
+        10155717009257919025,
+        14898021666898374032,
+        7860074398538203142,
+        2069992586654088266,
+    ]),
+    Scalar([
+        710872108799256274,
+        1159047865186959097,
+        6767191249542230941,
+        5107844015310419351,
+    ]),
+    Scalar([
+        2938267671777636984,
+        17283237539535455141,
+        6307556094619337201,
+        7215740500616219972,
+    ]),
+    Scalar([
+        13119965179413840322,
+        10009793553937538356,
+        11809743661840104807,
+        1036770593254850908,
+    ]),
+    Scalar([
+        12762913747436489920,
+        3129069140182875338,
+        479386867198314492,
+        2834837165148438729,
+    ]),
+    Scalar([
+        14114533355564077893,
+        4077883499281341272,
+        15835952693764315587,
+        6348339665509363994,
+    ]),
+    Scalar([
+        13562808070951857005,
+        17913986894972593433,
+        6472211148679125253,
+        1193559050268588411,
+    ]),
+    Scalar([
+        13702244173961424987,
+        5371033936897957793,
+        9966652825385278501,
+        3568762254976030942,
+    ]),
+    Scalar([
+        136618528083495336,
+        5869076111903763184,
+        6901182584954501185,
+        2918563434118712301,
+    ]),
+    Scalar([
+        10641269224869422376,
+        12108225399281217375,
+        7528240421936215231,
+        1174473401666881646,
+    ]),
+    Scalar([
+        9220841823147266431,
+        16337611254304324944,
+        3586682082629503028,
+        4800090908810791872,
+    ]),
+    Scalar([
+        12705363536936874727,
+        1090334033896091676,
+        2717889866609503963,
+        251636315568072230,
+    ]),
+    Scalar([
+        4289975935416628883,
+        714296739437522714,
+        1279080017701848022,
+        7646829412941163449,
+    ]),
+    Scalar([
+        3883191923589533179,
+        6006721169040624787,
+        15504438395437432960,
+        7099210777317004382,
+    ]),
+    Scalar([
+        15861225137292568349,
+        17379884749112027250,
+        8167858219425225971,
+        6692952890526970842,
+    ]),
+    Scalar([
+        17801108426414982382,
+        1161225463290743904,
+        891214603645081203,
+        1671338768232856651,
+    ]),
+    Scalar([
+        6984534765246336664,
+        3486138445981110488,
+        10696127271403872618,
+        2672741333885864142,
+    ]),
+    Scalar([
+        5593018675625113988,
+        17892896999774209026,
+        13510910184628793579,
+        4478358330323487350,
+    ]),
+    Scalar([
+        10988266183610677707,
+        3106664149553683940,
+        6361747709382142958,
+        3480649380267870105,
+    ]),
+    Scalar([
+        10416864478744919391,
+        14286619878899259635,
+        13948275604440531931,
+        1576767294734946050,
+    ]),
+    Scalar([
+        12870361684016264715,
+        9719918713983138123,
+        12798636015824249446,
+        7335170107286926774,
+    ]),
+    Scalar([
+        13959438233452435482,
+        11277975220013224537,
+        8591659525381288772,
+        5435192419971164669,
+    ]),
+    Scalar([
+        16704886458736879625,
+        8149421219635670862,
+        1669071424575034109,
+        5279739410933287102,
+    ]),
+    Scalar([
+        12789368478980770382,
+        5495462587099041947,
+        15741394935116541408,
+        5823116249253353790,
+    ]),
+    Scalar([
+        15825886129105401996,
+        6448210775704766042,
+        11918703411591228868,
+        6822810614306695280,
+    ]),
+    Scalar([
+        1689488015101226486,
+        5668136914814498504,
+        3156812492294044565,
+        6280510534838607154,
+    ]),
+    Scalar([
+        3542642933475861049,
+        1583350755233919505,
+        3923860273056864290,
+        5560917700252625739,
+    ]),
+    Scalar([
+        5120205221978013472,
+        2456784252189602469,
+        2200477963983715631,
+        267001902795317235,
+    ]),
+    Scalar([
+        6119098722949186554,
+        11413674897814693428,
+        12587473046417982653,
+        4759169532180717668,
+    ]),
+    Scalar([
+        10942590427391730950,
+        14556373866117355950,
+        2685037521062632984,
+        7943379782761764237,
+    ]),
+    Scalar([
+        10405596831321275487,
+        3956652705160553054,
+        2138248272742463120,
+        336895494953886383,
+    ]),
+    Scalar([
+        7324213196480141877,
+        14360830569801995610,
+        8008862707480204,
+        4162861762294094408,
+    ]),
+    Scalar([
+        12420899075690817039,
+        6254589419972807832,
+        5292373811142753320,
+        2111598625172500767,
+    ]),
+    Scalar([
+        5553808545225806109,
+        14225103034027648112,
+        15601934366759961715,
+        7678519887747236367,
+    ]),
+    Scalar([
+        18410825972373263654,
+        1026800753515424997,
+        10252292891148630000,
+        5903369804692584826,
+    ]),
+    Scalar([
+        9342579108168926101,
+        17421426266945523286,
+        13745211398010496322,
+        5300211169644988667,
+    ]),
+    Scalar([
+        12393062037097646482,
+        6611070073687032411,
+        1901384205279281257,
+        4378098686646966494,
+    ]),
+    Scalar([
+        10204592590229087139,
+        12179913839500924764,
+        5089400167278060725,
+        4452047287632594490,
+    ]),
+    Scalar([
+        15284535520680180805,
+        12248456869000025573,
+        8719448339559669456,
+        4655549092616258264,
+    ]),
+    Scalar([
+        7158663149795372797,
+        14804183231071177175,
+        2964419990327747909,
+        4488442655043850196,
+    ]),
+    Scalar([
+        11877684822736521235,
+        1143626804493363245,
+        1334382659560000381,
+        460866602458637225,
+    ]),
+    Scalar([
+        6870310797207387191,
+        18292580527060220761,
+        6934800277080500839,
+        5549773216107195198,
+    ]),
+    Scalar([
+        9010896650937126813,
+        11478828277108835985,
+        16019575284744993590,
+        2224263856758213301,
+    ]),
+    Scalar([
+        6303833461291707680,
+        4615186625429322118,
+        5694929331582477586,
+        2467689564503178586,
+    ]),
+    Scalar([
+        9130342326230309623,
+        14738253047200182455,
+        7953109165242251115,
+        7901024211983044347,
+    ]),
+    Scalar([
+        12577399333369436856,
+        6859442510628198924,
+        5639363488587657456,
+        6810421488261081454,
+    ]),
+    Scalar([
+        9469164853825504989,
+        9148288770883354792,
+        14017687824459587270,
+        1251107826988097949,
+    ]),
+    Scalar([
+        2621093305134330438,
+        8591183721845448291,
+        14219908614768800270,
+        6886324939740589426,
+    ]),
+    Scalar([
+        14828188424690353295,
+        3799533565903148614,
+        7983683343254639299,
+        7262101379245916383,
+    ]),
+    Scalar([
+        10106039794909161995,
+        4981507483582952135,
+        542007235347663090,
+        744731128439971686,
+    ]),
+    Scalar([
+        5592922119995484791,
+        15253349670692977917,
+        15081732874085108916,
+        6262862739622475181,
+    ]),
+    Scalar([
+        4397593542774695256,
+        15419258110358633845,
+        15804845405671337238,
+        5899224232872146247,
+    ]),
+    Scalar([
+        5425978411437102791,
+        7051076044277783868,
+        10931083639437719012,
+        1952264026210797456,
+    ]),
+    Scalar([
+        14354318527462642052,
+        8449094794723581087,
+        8909322736599396497,
+        3924732134061435629,
+    ]),
+    Scalar([
+        13569366636355300377,
+        17673442181819866397,
+        2027702366571829564,
+        4672730153069026395,
+    ]),
+    Scalar([
+        15440854767137666123,
+        10156191948377259635,
+        1559697292573884697,
+        3260260717269868463,
+    ]),
+    Scalar([
+        14521649059602708280,
+        3262968470629932054,
+        16523366838806654864,
+        5835487396153623922,
+    ]),
+    Scalar([
+        5925956269679315452,
+        2249050785148606956,
+        17859189412865557155,
+        903027299702383404,
+    ]),
+    Scalar([
+        3420064601101774372,
+        9412729904182324998,
+        10566763251754684293,
+        3908059706827320543,
+    ]),
+    Scalar([
+        8882001140479840494,
+        16986305940426225241,
+        5181967243575196731,
+        7536420648517479220,
+    ]),
+    Scalar([
+        12662224883695768227,
+        16545847014603015641,
+        17245085728133381024,
+        2592638997771819352,
+    ]),
+    Scalar([
+        14564350011453710019,
+        10561019646641837171,
+        10305396325159813504,
+        6978685033814826220,
+    ]),
+    Scalar([
+        11420302106383079886,
+        14217400577380884628,
+        18346845617865893455,
+        7573598549208810054,
+    ]),
+    Scalar([
+        17251845103767242219,
+        3663477089731918518,
+        11963011474975212870,
+        7527775268499664708,
+    ]),
+    Scalar([
+        11488938293985421396,
+        2139452451834066972,
+        12910879828720180790,
+        578069910580516481,
+    ]),
+    Scalar([
+        4093392141907152961,
+        10868190219293482282,
+        1204427461018604431,
+        1080107395027136033,
+    ]),
+    Scalar([
+        6694116215277166689,
+        8033401034417571959,
+        8565357947220368727,
+        5704505591546876282,
+    ]),
+    Scalar([
+        10737109804222477359,
+        860262907293729136,
+        15336979840545092134,
+        1046385036993130658,
+    ]),
+    Scalar([
+        11505102935524444472,
+        7356760893907375584,
+        8609652130054356197,
+        4820633438993799512,
+    ]),
+    Scalar([
+        8416971500348850535,
+        13892769033111543647,
+        4939948488841012738,
+        5105052924768494511,
+    ]),
+    Scalar([
+        17949100654528147337,
+        10784278247692168017,
+        6551236104591477237,
+        3364850903082180773,
+    ]),
+    Scalar([
+        17769858572308897100,
+        3317731467082996151,
+        12531998812784608139,
+        4173757343587414774,
+    ]),
+    Scalar([
+        1073434036697620734,
+        7134806781034282482,
+        9239011163279305724,
+        5273336093749853745,
+    ]),
+    Scalar([
+        16704126849463694863,
+        13102533403838392523,
+        4953085577485950163,
+        2909699563377615872,
+    ]),
+    Scalar([
+        871639465766553050,
+        15410356232346878790,
+        10193574286344729316,
+        8116932261927089040,
+    ]),
+    Scalar([
+        8052143940354379694,
+        517381075813814137,
+        3716534918991093104,
+        7131143208216648652,
+    ]),
+    Scalar([
+        7819265589659661695,
+        5030984062418093571,
+        2853810314043075657,
+        1495299198493076825,
+    ]),
+    Scalar([
+        14995644498472447692,
+        6710992691987112314,
+        1813534217791465319,
+        4698755051472988644,
+    ]),
+    Scalar([
+        10227051533203983303,
+        11143117351591607690,
+        1747090413935657334,
+        7742729080724268091,
+    ]),
+    Scalar([
+        6404683944171909099,
+        17065250760225006851,
+        4016789812186230200,
+        7684884205665476460,
+    ]),
+    Scalar([
+        7642092042166071295,
+        12340298291023993578,
+        6568601099898305710,
+        2066598821065594850,
+    ]),
+    Scalar([
+        2414497062642276025,
+        1051071936935204724,
+        10194619164878361828,
+        5308995214602811160,
+    ]),
+    Scalar([
+        7747031357663797005,
+        2318693839027398925,
+        11765205800844142276,
+        602789118241736363,
+    ]),
+    Scalar([
+        12220308100642232933,
+        1531414713529960131,
+        971681580644639349,
+        6082549278735487612,
+    ]),
+    Scalar([
+        11605254881021790898,
+        9811684359153222551,
+        9175676977326088061,
+        7049932299257159728,
+    ]),
+    Scalar([
+        9668362294485962545,
+        18011712897508222016,
+        2345199886066294226,
+        8213491011188173158,
+    ]),
+    Scalar([
+        7437952329710759518,
+        12377316438712444797,
+        6462452621902469251,
+        611167677053692914,
+    ]),
+    Scalar([
+        7960945155297492795,
+        16003330616891961272,
+        8801273032955878757,
+        800344253554116494,
+    ]),
+    Scalar([
+        5207952045600287229,
+        4079286253362772343,
+        9615323749632183397,
+        6911936544963240974,
+    ]),
+    Scalar([
+        8685808681603500125,
+        5550658146039231491,
+        12830635583062588874,
+        4092658311700114747,
+    ]),
+    Scalar([
+        17092685815840228651,
+        1617051283443857104,
+        10326042012277123307,
+        4300022376883159498,
+    ]),
+    Scalar([
+        14610409360619347833,
+        7077824935919805322,
+        1191330302360865660,
+        5189622203288605432,
+    ]),
+    Scalar([
+        11878851485160083728,
+        10454392366354041781,
+        4039211559525414743,
+        3480485580813745493,
+    ]),
+    Scalar([
+        15108645771279662220,
+        16005413798653628738,
+        14206014560890185054,
+        2177451307264842604,
+    ]),
+    Scalar([
+        902382031987150348,
+        11090723036265756791,
+        8457245923840907226,
+        1330368851139689479,
+    ]),
+    Scalar([
+        11326811484877122596,
+        3912128258575619527,
+        9804393827162596393,
+        7759932345451942060,
+    ]),
+    Scalar([
+        3407388323828629573,
+        8543596462657490993,
+        4390840565148730158,
+        6410095935282689123,
+    ]),
+    Scalar([
+        1333837166551535371,
+        644017275437899305,
+        11596118747348234004,
+        6536498394899942621,
+    ]),
+    Scalar([
+        4042753198889028587,
+        11104764650401346219,
+        8523707798885928523,
+        4824523386821668873,
+    ]),
+    Scalar([
+        4063868166548719781,
+        15486392979979208396,
+        11641522149139472684,
+        3225536759413372144,
+    ]),
+    Scalar([
+        16576138999879112229,
+        11314123733395936142,
+        1013143502405086277,
+        1698998309467051017,
+    ]),
+    Scalar([
+        12768036288092709368,
+        14293242809109426722,
+        223384020046943970,
+        6852079118911022822,
+    ]),
+    Scalar([
+        16108607166056414758,
+        11131802954404755689,
+        12033754470521484802,
+        1598035705494746225,
+    ]),
+    Scalar([
+        1437498988856498764,
+        11948311380831719360,
+        3535314735881294337,
+        5268604702690489074,
+    ]),
+    Scalar([
+        5442713209646520840,
+        8994282531644053258,
+        4993475355155893728,
+        7506768799917305074,
+    ]),
+    Scalar([
+        12351898932975262367,
+        9698060807111417707,
+        17121996891536574270,
+        5651708657741361247,
+    ]),
+    Scalar([
+        15184997680322124231,
+        7762578014834978248,
+        4384177051284205096,
+        4212301510311562275,
+    ]),
+    Scalar([
+        10342682956156884155,
+        2291625049494134395,
+        1566993935900236330,
+        6570954380726206706,
+    ]),
+    Scalar([
+        16605298622955452005,
+        7874679301940938281,
+        16989473842081609128,
+        521309518856734483,
+    ]),
+    Scalar([
+        1468167829595069612,
+        7719442200111792925,
+        7903790650628541787,
+        4177374888898722189,
+    ]),
+    Scalar([
+        7300153929802748713,
+        3123243740169104435,
+        1582554040400492092,
+        4474572839425754314,
+    ]),
+    Scalar([
+        8062222667586184126,
+        3227355526190528326,
+        14597434954299974793,
+        7432126509486064487,
+    ]),
+    Scalar([
+        8044759911016554159,
+        7584500937341021561,
+        12434198373105825420,
+        5935962890850666575,
+    ]),
+    Scalar([
+        13411048979322938372,
+        18227463950084436086,
+        15390373943390772461,
+        7568878624861136026,
+    ]),
+    Scalar([
+        16064144518302560445,
+        4759963493783003463,
+        11778506875567529834,
+        5896496505507055382,
+    ]),
+    Scalar([
+        17804369608637004931,
+        5665879495005490978,
+        8268630954426700876,
+        1789157806960231648,
+    ]),
+    Scalar([
+        1064730400054148937,
+        11178246705831601325,
+        2907106437908906165,
+        9565862733956342,
+    ]),
+    Scalar([
+        3501130297813293003,
+        1203728542698780946,
+        9506486540638435509,
+        4253119561240980441,
+    ]),
+    Scalar([
+        13768564004487691335,
+        8856234506857297214,
+        10413049939645795414,
+        4820015195256180411,
+    ]),
+    Scalar([
+        9735748596782884319,
+        14891268341851245331,
+        6610269596622761500,
+        1018464902813529486,
+    ]),
+    Scalar([
+        14697279210055040896,
+        5134707184259943501,
+        13629740953211774555,
+        2217079783017773064,
+    ]),
+    Scalar([
+        3165477281082844256,
+        15119559583480000252,
+        14440116748559404492,
+        389887991157385830,
+    ]),
+    Scalar([
+        14227114495121077071,
+        4184466118642510614,
+        9534708199366148687,
+        4381173541933581064,
+    ]),
+    Scalar([
+        2975733265435206811,
+        3404771725886238909,
+        12089173019311675709,
+        3385508093148822010,
+    ]),
+    Scalar([
+        7693964601016709739,
+        14682437163506116369,
+        9599723096687945384,
+        2731290713495243122,
+    ]),
+    Scalar([
+        8194368739406372158,
+        17894661650116609588,
+        2237619534142056095,
+        2593369174881170780,
+    ]),
+    Scalar([
+        9520666498658734194,
+        5452152600277187597,
+        15349106874922297468,
+        4634851323482377744,
+    ]),
+    Scalar([
+        16112254796879382690,
+        3385434629509157414,
+        1864317768658874480,
+        6307824517553362416,
+    ]),
+    Scalar([
+        9182961675711198251,
+        12847421965486241252,
+        12178188129299370617,
+        7154924689017958531,
+    ]),
+    Scalar([
+        4767975124384458701,
+        456067545144881646,
+        17276605733428590132,
+        3296153518729787440,
+    ]),
+    Scalar([
+        2967894137369335871,
+        13071142220423950613,
+        6918031909863745239,
+        446284961190769321,
+    ]),
+    Scalar([
+        11769229473711116421,
+        7518485450033931597,
+        4140409530724394702,
+        1048613334653621536,
+    ]),
+    Scalar([
+        12784348166545031754,
+        14266530138273193939,
+        14664470493560647000,
+        708145333282708707,
+    ]),
+    Scalar([
+        577149051742709728,
+        1077106371645658799,
+        2991774620939933626,
+        6350505185112704529,
+    ]),
+    Scalar([
+        10934009770796124951,
+        17462883694170366454,
+        7162405842524374278,
+        5252256177511128388,
+    ]),
+    Scalar([
+        3021073420533226526,
+        17534685065180971722,
+        15227967080112716402,
+        3402549629976117388,
+    ]),
+    Scalar([
+        5884336585372329082,
+        4232643930026655536,
+        12324151453878737840,
+        8024221389562096978,
+    ]),
+    Scalar([
+        7661457669681879196,
+        12408727250052227887,
+        13618748618326564942,
+        7259002827931585309,
+    ]),
+    Scalar([
+        1824982583204787161,
+        676981368656180869,
+        224404894063064295,
+        936162357388575882,
+    ]),
+    Scalar([
+        6230141241507439694,
+        11798837771686168044,
+        10170523897546546362,
+        1658860230297611748,
+    ]),
+    Scalar([
+        1160988262163316687,
+        4387061965968462896,
+        2854972304574957103,
+        2125430400250033664,
+    ]),
+    Scalar([
+        1948201214362205895,
+        2417023059347342278,
+        7005635343081385464,
+        8164959137443425245,
+    ]),
+    Scalar([
+        7498207921852347280,
+        7736820206888929965,
+        2515114573940471701,
+        1410372593839904380,
+    ]),
+    Scalar([
+        14796519555689487057,
+        12332000431113102957,
+        16081024370523799568,
+        5840938767095497483,
+    ]),
+    Scalar([
+        16579128009065847189,
+        1212060227490319302,
+        7241976941421086638,
+        4087396671547576457,
+    ]),
+    Scalar([
+        5371274406381833966,
+        11370786298056890629,
+        17364472251511167578,
+        6637985861440151210,
+    ]),
+    Scalar([
+        14047287681657626376,
+        2701716699224062816,
+        594271465942834997,
+        6994270771689779297,
+    ]),
+    Scalar([
+        522624062364713028,
+        7740437274842478727,
+        1541218489041394645,
+        3491090700050538602,
+    ]),
+    Scalar([
+        3411067585103594524,
+        16761246349857903287,
+        13794345573492529949,
+        5319059862281151391,
+    ]),
+    Scalar([
+        13283310760390156465,
+        18010467408714696314,
+        16987679581113997056,
+        6865839047560488460,
+    ]),
+    Scalar([
+        9255751204935403571,
+        1163692729373860709,
+        14500440234357757271,
+        1874762864115028108,
+    ]),
+    Scalar([
+        2955781993243992975,
+        6213188865717092588,
+        10509862438250198575,
+        4310900387053025105,
+    ]),
+    Scalar([
+        16274729255186489642,
+        4316877618463969524,
+        8230290188841004127,
+        4953224553852128757,
+    ]),
+    Scalar([
+        3071773074881007144,
+        944570646370737777,
+        15421147694640718312,
+        3049775903935516535,
+    ]),
+    Scalar([
+        15880881883554466227,
+        12897811742716550797,
+        17419521062426023450,
+        3000779280404053691,
+    ]),
+    Scalar([
+        4412133397652449042,
+        2409843387197658079,
+        8762991050112759348,
+        2234298885294895218,
+    ]),
+    Scalar([
+        4134384564468979238,
+        12597283926268774682,
+        15396073932541420657,
+        4857917640110854910,
+    ]),
+    Scalar([
+        3988434981447019887,
+        9107189481826958867,
+        2858535751909761030,
+        7771478258301239778,
+    ]),
+    Scalar([
+        4881383669587786691,
+        13703096195011914703,
+        8502726866786897866,
+        508909821641240878,
+    ]),
+    Scalar([
+        293929353347956654,
+        11712637725306410053,
+        5454139913617116074,
+        5258755083133051674,
+    ]),
+    Scalar([
+        10703864405106854135,
+        15262621480083095770,
+        6305642283236917452,
+        5814670359575195715,
+    ]),
+    Scalar([
+        6703248759778324150,
+        7858589383889047960,
+        11992735786148617699,
+        1107781911600505827,
+    ]),
+    Scalar([
+        3352426506222669738,
+        10740187257312364975,
+        7381359590289414484,
+        3254902507048798442,
+    ]),
+    Scalar([
+        2084767520075809060,
+        12564232503165901207,
+        120914735537696450,
+        4588368566022524635,
+    ]),
+    Scalar([
+        10796515964065697385,
+        13243148125175662526,
+        16966944008125607074,
+        2804275231290273674,
+    ]),
+    Scalar([
+        13549349791553927012,
+        5080919580916839627,
+        13500582961478425676,
+        3778803271587680553,
+    ]),
+    Scalar([
+        12465159572762192414,
+        15335776110678972945,
+        9538710931267564016,
+        1900153852696945832,
+    ]),
+    Scalar([
+        6335216343442540800,
+        5546654782395547249,
+        7982004053459965481,
+        509581628823034036,
+    ]),
+    Scalar([
+        8448334550526401629,
+        6535543475294631625,
+        1369273026892877435,
+        833928361968343130,
+    ]),
+    Scalar([
+        2306554704408843677,
+        9334979152030627460,
+        545611349134762278,
+        7103848326828724615,
+    ]),
+    Scalar([
+        11799192893188137866,
+        10730872215142278129,
+        16515756259234277786,
+        5935566571349158378,
+    ]),
+    Scalar([
+        616140506896802614,
+        4114795110716817648,
+        17912540546015822103,
+        6579997965133862017,
+    ]),
+    Scalar([
+        1022843315401130387,
+        1875565468667319609,
+        313231484872144687,
+        3023832110715049401,
+    ]),
+    Scalar([
+        6032595610163478277,
+        9944096568353913781,
+        7791688479105564357,
+        7616432726220707838,
+    ]),
+    Scalar([
+        8455171911111833676,
+        17793791238367071688,
+        11272710275888061922,
+        2530606936433382459,
+    ]),
+    Scalar([
+        10983015671435360645,
+        18070619389952834741,
+        9532431868379363556,
+        4363069129038005862,
+    ]),
+    Scalar([
+        10959852593320951582,
+        11099944460997143064,
+        17491967157768327401,
+        2402639214688229089,
+    ]),
+    Scalar([
+        10126438882104446772,
+        6349737543519612676,
+        17720467944587278925,
+        7895639593454241020,
+    ]),
+    Scalar([
+        8598636287149209509,
+        7771417296671461494,
+        4626471528560457373,
+        7593946465260802651,
+    ]),
+    Scalar([
+        7854384259751820068,
+        17083500928796335801,
+        11587671088111162805,
+        7606124123299234604,
+    ]),
+    Scalar([
+        8965601103248655884,
+        17975074679346500452,
+        4988547073984415196,
+        1534254651649103991,
+    ]),
+    Scalar([
+        13296694327546395471,
+        17281636409519024748,
+        3723344078924287762,
+        6362868382848156980,
+    ]),
+    Scalar([
+        11654503028061806758,
+        995892272745320552,
+        15923688074900327511,
+        1970993799003022909,
+    ]),
+    Scalar([
+        12970267915834475572,
+        13487963711695105401,
+        9234404852380475646,
+        5577812333584947998,
+    ]),
+    Scalar([
+        5141751891041538043,
+        13877101987236579324,
+        3567594461610243014,
+        2778235424600903411,
+    ]),
+    Scalar([
+        8956141852380766718,
+        1049661722415973770,
+        12509471659317171642,
+        2330667925810374791,
+    ]),
+    Scalar([
+        18196875429777698221,
+        12545408232532251514,
+        15937789748005510198,
+        4875347128716675546,
+    ]),
+    Scalar([
+        11084498632694324823,
+        1314546600402982423,
+        18235944689181914935,
+        2858424383541214149,
+    ]),
+    Scalar([
+        13846803525343987581,
+        13338785903721262204,
+        12126433369887890708,
+        416063377949873008,
+    ]),
+    Scalar([
+        13160969940205162916,
+        4623335410554579147,
+        8012743910451000906,
+        4485839497170160546,
+    ]),
+    Scalar([
+        9809418883692214404,
+        5313019033280181460,
+        10254726301242242798,
+        3720712240717628948,
+    ]),
+    Scalar([
+        7634142808431975216,
+        15293589265444158531,
+        2820248705517617882,
+        14080687609634826,
+    ]),
+    Scalar([
+        11027501822067187056,
+        5612968722181240459,
+        15571556850866480735,
+        648033226593438534,
+    ]),
+    Scalar([
+        7378878063662776823,
+        5142616293292078880,
+        8341376761469018358,
+        2902014238864177633,
+    ]),
+    Scalar([
+        6174142106233469078,
+        6404183492914107030,
+        12822067309253933146,
+        4899386890765550912,
+    ]),
+    Scalar([
+        17102118695782735625,
+        10158431758002670020,
+        9080771684234897011,
+        2501663780517548218,
+    ]),
+    Scalar([
+        9840774874681527418,
+        9860082165975227282,
+        2805701683332232585,
+        5814196660606014560,
+    ]),
+    Scalar([
+        7272008989745086777,
+        11658929142488054990,
+        6537357128987277038,
+        303997132011606496,
+    ]),
+    Scalar([
+        9510263270003729496,
+        5464864040051927010,
+        3788497461510238730,
+        218278286041538144,
+    ]),
+    Scalar([
+        880387524679554387,
+        1545060327419732553,
+        13407777451308185298,
+        8054448802604814351,
+    ]),
+    Scalar([
+        13786890940502560857,
+        9703538787939571759,
+        13647015112888516456,
+        875716116612479381,
+    ]),
+    Scalar([
+        3603263319351923138,
+        2629106899351135120,
+        4073373934475306100,
+        8079988346432359725,
+    ]),
+    Scalar([
+        1877447143834516248,
+        3312429265816344034,
+        2959019782029944825,
+        5872031973423239187,
+    ]),
+    Scalar([
+        12177440020088641838,
+        16335427944170463473,
+        18415320079203593002,
+        6920998598198714961,
+    ]),
+    Scalar([
+        458410038717795929,
+        10076789609093286743,
+        534341328017200051,
+        5868737258865355793,
+    ]),
+    Scalar([
+        9322894635557937842,
+        10437894380210140417,
+        8352680001278338902,
+        3327672932581238937,
+    ]),
+    Scalar([
+        5552670790629646843,
+        13110252214648794835,
+        2334284289355848765,
+        2280801283316584529,
+    ]),
+    Scalar([
+        14471721070094269987,
+        7979802298526225574,
+        14792181611781814097,
+        3771058413746030768,
+    ]),
+    Scalar([
+        9986815667405054083,
+        8462731015097158537,
+        9840439740110315599,
+        7247591788196252771,
+    ]),
+    Scalar([
+        4066229015933707100,
+        13789594518908895899,
+        5372404710236716324,
+        2700275294014560050,
+    ]),
+    Scalar([
+        421492355743211383,
+        9500336052541044808,
+        17167929088523198985,
+        4693996744872284539,
+    ]),
+    Scalar([
+        15776236804199184823,
+        14828336687279026826,
+        10611646865846986410,
+        2790641422033181117,
+    ]),
+    Scalar([
+        6056094338605097789,
+        13972184995807818932,
+        12243144664464799101,
+        204327841099961061,
+    ]),
+    Scalar([
+        18368435286014257510,
+        7299087739756678243,
+        18096671392440259284,
+        8069137504364242168,
+    ]),
+    Scalar([
+        10485112177824348594,
+        14651679204259528999,
+        2216763845630243438,
+        1713564998608806327,
+    ]),
+    Scalar([
+        11024943420951192918,
+        17784699958066017249,
+        9270871868024483513,
+        6202979106577037838,
+    ]),
+    Scalar([
+        16768114077497275596,
+        5224005038507097392,
+        12686553857844837279,
+        6850590362019366270,
+    ]),
+    Scalar([
+        3186701932404028062,
+        4432252656408597001,
+        8624887230067245839,
+        74548315243917114,
+    ]),
+    Scalar([
+        16958445944753586692,
+        17386212256920491226,
+        11226857907181715357,
+        6352740981281928184,
+    ]),
+    Scalar([
+        13533865705067955689,
+        2193475352361695449,
+        11304948630593913614,
+        4779879650106466081,
+    ]),
+    Scalar([
+        10490565795938234470,
+        7940109129388200836,
+        624637564104243843,
+        3870932266466743238,
+    ]),
+    Scalar([
+        11698173450893649103,
+        1785115475766261394,
+        13991754758490848149,
+        5918415729341641171,
+    ]),
+    Scalar([
+        17727017117597545048,
+        14151283956793061200,
+        6179440511783339935,
+        247698037837557057,
+    ]),
+    Scalar([
+        12043601805915066665,
+        18307924351392013868,
+        1618075677951212435,
+        2263090459577500573,
+    ]),
+    Scalar([
+        5518246526172587400,
+        6558864665572482994,
+        9284996839795119882,
+        4079799005250025266,
+    ]),
+    Scalar([
+        9547686176003613833,
+        8998027278475304575,
+        3664492102016378036,
+        1295442352887376795,
+    ]),
+    Scalar([
+        3627382903854456064,
+        17144377382931412426,
+        12418524059906085367,
+        8227857176000434509,
+    ]),
+    Scalar([
+        1845731572547432273,
+        17202219003163568776,
+        12235954614744167115,
+        6632013112923799101,
+    ]),
+    Scalar([
+        3265505947218031528,
+        3049334080546477190,
+        7935446718952111060,
+        7120180278828663700,
+    ]),
+    Scalar([
+        8210853981789871898,
+        11548107713857287454,
+        16530018609288259765,
+        4076694886071993510,
+    ]),
+    Scalar([
+        10466368592271013796,
+        11521291426799797929,
+        3463805816415074966,
+        3272696125079553887,
+    ]),
+    Scalar([
+        272153490068564330,
+        196162991407151823,
+        1263705939825372249,
+        6291235621771030257,
+    ]),
+    Scalar([
+        14839537952776786100,
+        2160249731093953892,
+        10543662859512291883,
+        7515047064017415409,
+    ]),
+    Scalar([
+        12798592116718187360,
+        15868522698022593305,
+        3505288226691575938,
+        8294741150227361425,
+    ]),
+    Scalar([
+        9717320074897380709,
+        12702761944901888561,
+        7861876839876544128,
+        6003165000892717997,
+    ]),
+    Scalar([
+        2370080664336376846,
+        4569987537437503687,
+        9532809819941442445,
+        4171851214743825655,
+    ]),
+    Scalar([
+        14522640185003820257,
+        12502185053104435613,
+        5140671274511529343,
+        5743170804815202302,
+    ]),
+    Scalar([
+        17110571435586266397,
+        4680612660574212975,
+        11448267519920566493,
+        3871406994658283562,
+    ]),
+    Scalar([
+        13242300611976245871,
+        9735506249882995503,
+        8416805338726283006,
+        2871650288494420397,
+    ]),
+    Scalar([
+        6918562152053785831,
+        13915521801985740988,
+        11248006718330884328,
+        5985799673048115749,
+    ]),
+    Scalar([
+        7470944876708981840,
+        771928675258359732,
+        17246431537137274959,
+        4674422085445488258,
+    ]),
+    Scalar([
+        8802849636401910381,
+        3792594469815013048,
+        14267282076986023047,
+        6669448661640690552,
+    ]),
+    Scalar([
+        9622898689998318034,
+        10090477555037184915,
+        4431852448411369387,
+        2454290996077547774,
+    ]),
+    Scalar([
+        1211984538588351148,
+        10478722496524314763,
+        7982840720346431139,
+        1435146236629716828,
+    ]),
+    Scalar([
+        4894895016999758358,
+        311744839563906736,
+        431669567871939119,
+        666891828405428526,
+    ]),
+    Scalar([
+        17831544448203001233,
+        14367922563073251103,
+        3919792338063709568,
+        463659202581435214,
+    ]),
+    Scalar([
+        4594236167861301688,
+        10669229444626796680,
+        14879708146370864607,
+        3917796018373119441,
+    ]),
+    Scalar([
+        9316081971268059468,
+        1795659113822559031,
+        6859841793455269659,
+        3066615951097225734,
+    ]),
+    Scalar([
+        13897749500549899560,
+        2100614449668689194,
+        6581265474427814478,
+        6717529624055339946,
+    ]),
+    Scalar([
+        4432470711826432749,
+        580998287937451366,
+        10709767823762463880,
+        3446043247971909153,
+    ]),
+    Scalar([
+        12496572623942693942,
+        13910944139034975794,
+        12109155807902611657,
+        5987782346095416189,
+    ]),
+    Scalar([
+        4724544769116402411,
+        8838264826677109938,
+        12588403346137056830,
+        7330269804628448756,
+    ]),
+    Scalar([
+        487179482137393455,
+        11147921087737186421,
+        8466171584882468234,
+        922717129417044963,
+    ]),
+    Scalar([
+        9195005731178361193,
+        14826244159402147879,
+        5045618307824554307,
+        6919078412023117796,
+    ]),
+    Scalar([
+        17410661907572714638,
+        18090608531669958330,
+        461860248870191190,
+        7066794894415409731,
+    ]),
+    Scalar([
+        16584442309797071137,
+        11394419855901928440,
+        5159351587222268173,
+        1594309779327873342,
+    ]),
+    Scalar([
+        17463539391592125986,
+        2875091702033865335,
+        7675425486212939780,
+        3527784909044601155,
+    ]),
+    Scalar([
+        10429109838923173473,
+        18209351301776199481,
+        5534927165103591923,
+        1131098871500054097,
+    ]),
+    Scalar([
+        14110014220052897777,
+        9947581304129657228,
+        11658214977124712551,
+        8107609996894149944,
+    ]),
+    Scalar([
+        9391323608531960466,
+        14715726286603613792,
+        6797557176415290142,
+        7127976975866302091,
+    ]),
+    Scalar([
+        16481545748493324453,
+        11443527077005328660,
+        16766053158572928169,
+        8208610143302210425,
+    ]),
+    Scalar([
+        3054027582094858225,
+        14539601624117189452,
+        4966676325298738037,
+        4872130555169667281,
+    ]),
+    Scalar([
+        4679077646126857479,
+        2728153735025985863,
+        536942023777028162,
+        7686820215183349753,
+    ]),
+    Scalar([
+        17838321695196134294,
+        15794067914558912111,
+        7078447670788831705,
+        2903735486609006142,
+    ]),
+    Scalar([
+        8000652888663596942,
+        15623350651846474490,
+        9729346972663070580,
+        6599472693014382586,
+    ]),
+    Scalar([
+        5773996771907102327,
+        15608261040701649852,
+        15562620446999115043,
+        5361447042510474677,
+    ]),
+    Scalar([
+        6135346796321181275,
+        4449364643355179427,
+        324137008875339303,
+        4203380936617632078,
+    ]),
+    Scalar([
+        17902353755760705908,
+        6112352061885680139,
+        11018362739960646670,
+        4313123662722204019,
+    ]),
+    Scalar([
+        3739920045577644602,
+        5989299090101912830,
+        17906923021687711291,
+        1681908734243093757,
+    ]),
+    Scalar([
+        8850788572452108717,
+        6359174808106479703,
+        8079660344694638456,
+        5891550288954446805,
+    ]),
+    Scalar([
+        14268083005391200673,
+        10154262082731785903,
+        10902111696504223292,
+        245361509728836221,
+    ]),
+    Scalar([
+        16474682144559133550,
+        4591190944888127807,
+        7601176916741526448,
+        6958668052779288695,
+    ]),
+    Scalar([
+        2571276756748015195,
+        1683431459162099141,
+        13874359074342721717,
+        5115258303630648387,
+    ]),
+    Scalar([
+        15553465136645856017,
+        11534490309548828652,
+        2911622825425457672,
+        1684318426777327351,
+    ]),
+    Scalar([
+        16397216414399290985,
+        15858300972955110396,
+        16118695378441649736,
+        6953677103821511474,
+    ]),
+    Scalar([
+        10297230169040116896,
+        17276312760826307820,
+        18080919776320998738,
+        3473670963003997115,
+    ]),
+    Scalar([
+        2374941111818850576,
+        15935899421867763568,
+        16323535592152095384,
+        5976051922947085797,
+    ]),
+    Scalar([
+        7756258951773565025,
+        16205638994469138031,
+        10604433720426397206,
+        6006238559369093117,
+    ]),
+    Scalar([
+        7757953330237112865,
+        4232684379001989301,
+        4978107578023596616,
+        5336977942592546975,
+    ]),
+    Scalar([
+        3967543654501436404,
+        4666072008902496941,
+        3206240378567585539,
+        4729184254675346246,
+    ]),
+    Scalar([
+        9456213364386637642,
+        10263468203513628434,
+        15474926648672207196,
+        5707985519924057525,
+    ]),
+    Scalar([
+        12246361067772918856,
+        11678813376848506314,
+        1900313613443249346,
+        5359078641860181856,
+    ]),
+    Scalar([
+        6945017099393231559,
+        1578190723356354393,
+        5270842839874079654,
+        3300543536097070334,
+    ]),
+    Scalar([
+        5788364592287959180,
+        13231515345751169159,
+        17704245833561459126,
+        2476651882108168531,
+    ]),
+    Scalar([
+        6836672323680278266,
+        16029750580068286393,
+        17466613737760044293,
+        4288320930407386124,
+    ]),
+    Scalar([
+        18341019612577621011,
+        6113424840299792683,
+        2936842784042176370,
+        2830039020864657091,
+    ]),
+    Scalar([
+        7100070857278876997,
+        4528717976250473203,
+        8330929491768973761,
+        1402250037038512243,
+    ]),
+    Scalar([
+        17381901046342685867,
+        17505708297701861330,
+        11259952111999902480,
+        7865428875789984850,
+    ]),
+    Scalar([
+        6759371764906054909,
+        2977440042713877898,
+        16694017531618925530,
+        5846712139564305194,
+    ]),
+    Scalar([
+        10554289771832462221,
+        11741198788870402883,
+        4197225845197870799,
+        4630774242570572052,
+    ]),
+    Scalar([
+        17742880004443502022,
+        14447483682972054292,
+        10828392207688532781,
+        4814487886717046587,
+    ]),
+    Scalar([
+        17555970671024404187,
+        9913009461832836761,
+        2958198524293806113,
+        5949344210677535034,
+    ]),
+    Scalar([
+        9822420993074361958,
+        6827508283638782522,
+        7161550285775176743,
+        5662529321281963691,
+    ]),
+    Scalar([
+        7973152621719465344,
+        16656265033190625369,
+        11073847485006552436,
+        3258485695826483848,
+    ]),
+    Scalar([
+        1510327609403424564,
+        16519770954325585457,
+        1200526415005592044,
+        3711399381667392710,
+    ]),
+    Scalar([
+        17674226996570619063,
+        1242615189926285009,
+        17386162447859333898,
+        5026632153653710560,
+    ]),
+    Scalar([
+        4869617494638546529,
+        14616913098886843698,
+        16297675034155217471,
+        8186053888666380729,
+    ]),
+    Scalar([
+        13952279795029116578,
+        2981614295100101938,
+        14353537701814209787,
+        5994588090431087782,
+    ]),
+    Scalar([
+        3719039131115522926,
+        6117864323560366781,
+        12786739979933847564,
+        7560484903492539682,
+    ]),
+    Scalar([
+        17476731789145725188,
+        4041881815161413496,
+        809057001742497833,
+        1684813054993885647,
+    ]),
+    Scalar([
+        10787166671232001174,
+        4141546041290993550,
+        8052546504238238847,
+        3063108529545726713,
+    ]),
+    Scalar([
+        6326567405617311613,
+        9167209203702288948,
+        12270693445996691024,
+        5543371630198684478,
+    ]),
+    Scalar([
+        11851045755378094125,
+        14523039726656405470,
+        8653958601452499181,
+        5028666172676926110,
+    ]),
+    Scalar([
+        389615027581157376,
+        5741314541639874244,
+        7004014624938555211,
+        1082855431587458359,
+    ]),
+    Scalar([
+        1789089822607626097,
+        11029648767368308914,
+        3714595440544521807,
+        7784914683139032079,
+    ]),
+    Scalar([
+        12317269178847888792,
+        4174793501448872828,
+        9886200698718810862,
+        4875089184745217890,
+    ]),
+    Scalar([
+        8128319129103900811,
+        11926755695544627288,
+        1193595178384882700,
+        655070857580621747,
+    ]),
+    Scalar([
+        1558417099411509702,
+        9008865574346054356,
+        4869855425958069777,
+        5268709421130614679,
+    ]),
+    Scalar([
+        9370571317759274153,
+        13555596230300414147,
+        11365235997989362626,
+        5199497187875406151,
+    ]),
+    Scalar([
+        16911016654214380933,
+        11950533569280421756,
+        14651778835963023583,
+        1394350906603447882,
+    ]),
+    Scalar([
+        6618561958418579172,
+        16383142324001454859,
+        10306864835595848062,
+        2318196723529822473,
+    ]),
+    Scalar([
+        16365668971815196666,
+        6498300187235363181,
+        14968470877681480816,
+        7851033164810656243,
+    ]),
+    Scalar([
+        16247490880131930989,
+        2745544196576303514,
+        6876381134976920333,
+        3167856012854066899,
+    ]),
+    Scalar([
+        3909249454037359493,
+        8973079248669051154,
+        7439497418532558154,
+        5642458347818431342,
+    ]),
+    Scalar([
+        14971199024393597228,
+        6374308683290710245,
+        9259312729179962314,
+        4055085717636885227,
+    ]),
+    Scalar([
+        15010330236046461989,
+        14028067778776614061,
+        5771845339802387590,
+        2916374528000691634,
+    ]),
+    Scalar([
+        12540748534777771346,
+        12747973354380269253,
+        1727101075865443747,
+        4565480294706369459,
+    ]),
+    Scalar([
+        4627625655682835051,
+        16851417933416946978,
+        12882071972095252458,
+        6318395891267589502,
+    ]),
+    Scalar([
+        661364371493493523,
+        14852473578638204252,
+        13095249415426372705,
+        4287053278862696607,
+    ]),
+    Scalar([
+        12075379731437238135,
+        8851525287508120355,
+        4647821123299916765,
+        6323209095159671125,
+    ]),
+    Scalar([
+        5996983984655361014,
+        4224124156765549516,
+        17966256968207161977,
+        7227341102897344514,
+    ]),
+    Scalar([
+        4223826098922292572,
+        9162902795553443366,
+        6067912560559678867,
+        7333084897194652934,
+    ]),
+    Scalar([
+        14623479639669945196,
+        15101347489781444660,
+        6147431900442994982,
+        3502498951756984964,
+    ]),
+    Scalar([
+        9495321572129477819,
+        276807577892286707,
+        571658242874356855,
+        2780822274935178810,
+    ]),
+    Scalar([
+        16190620754350670253,
+        6012821585889667056,
+        15294315318875979125,
+        3310879863317571500,
+    ]),
+    Scalar([
+        4015382557988114667,
+        18230872548011637022,
+        7977756775649110098,
+        842549063292025657,
+    ]),
+    Scalar([
+        6257875370780824897,
+        3624458044655850021,
+        8173141094362437827,
+        8304634584076300786,
+    ]),
+    Scalar([
+        13327647736565697378,
+        5048578324679091783,
+        13334684645721079454,
+        1566613497137295367,
+    ]),
+    Scalar([
+        5087925960606876910,
+        5685716752301934360,
+        16642132416472005667,
+        3500242686099912218,
+    ]),
+    Scalar([
+        8500160198597509837,
+        1787315695392377770,
+        12205079821747928328,
+        846282572389587694,
+    ]),
+    Scalar([
+        13348037990521404623,
+        11755525452117709535,
+        9974029231509328625,
+        8334898157562778652,
+    ]),
+    Scalar([
+        6632370856489135304,
+        18360537462952072378,
+        18375376039182041321,
+        3969130803155411719,
+    ]),
+    Scalar([
+        6128655321199320115,
+        15672241308269078953,
+        9817262877275035497,
+        1737507643308244010,
+    ]),
+    Scalar([
+        5821832194021239432,
+        1680698260334178881,
+        15381291346757854609,
+        4730908941634304992,
+    ]),
+    Scalar([
+        5367454154915340,
+        15566881914754577160,
+        13010864007331982239,
+        8278422243760857031,
+    ]),
+    Scalar([
+        1634046068646917140,
+        7368147883842767427,
+        2660773610638444165,
+        1669697782334930740,
+    ]),
+    Scalar([
+        2403684813594349289,
+        11809402878270314442,
+        1607752319343182239,
+        7228269456375620265,
+    ]),
+    Scalar([
+        3804927883698534702,
+        3716731690543468222,
+        7666516808077893401,
+        7172366321255750869,
+    ]),
+    Scalar([
+        14381466649095976188,
+        1219096884150239114,
+        5781552767096798988,
+        3083869061328525421,
+    ]),
+    Scalar([
+        16754628063510913311,
+        3659817125417222845,
+        15848588342490646253,
+        926135787395025454,
+    ]),
+    Scalar([
+        1900366724121440555,
+        4961761081649750739,
+        6362339288337649031,
+        3111345557124091433,
+    ]),
+    Scalar([
+        11619753800139267502,
+        3032453480945936498,
+        1739060606628083002,
+        5078302460124091726,
+    ]),
+    Scalar([
+        5319901395411231069,
+        8316084884915409796,
+        115465674253740494,
+        7287838295788778567,
+    ]),
+    Scalar([
+        11310416293980941960,
+        6235552783343075059,
+        8033332336435580308,
+        8083024466909276686,
+    ]),
+    Scalar([
+        7476764857194970607,
+        6786572358297509985,
+        11527451013882785952,
+        3505436336867177718,
+    ]),
+    Scalar([
+        10775489692990592457,
+        4712253065613598889,
+        16187574974859993883,
+        7478731755172362360,
+    ]),
+    Scalar([
+        2522762433943747111,
+        11181944812539452862,
+        13997021828968624670,
+        1366152052168787158,
+    ]),
+    Scalar([
+        8084855156698119776,
+        16715547329034809055,
+        7090799024212109728,
+        3617714506496900532,
+    ]),
+    Scalar([
+        17405561210895957976,
+        16708143965786200326,
+        2347923612789080281,
+        7094632499151893277,
+    ]),
+    Scalar([
+        10049328736081019406,
+        5782027245942954298,
+        11411229870967856886,
+        1299698459786099815,
+    ]),
+    Scalar([
+        1405007484524717433,
+        17142414271830886542,
+        15559086904187916797,
+        18541090825074404,
+    ]),
+    Scalar([
+        6612607075099424947,
+        2666533167325756807,
+        17346653423923296586,
+        6080272820517685468,
+    ]),
+    Scalar([
+        17418665746467370853,
+        16806841232152384070,
+        15170226717394984675,
+        1546615932637908084,
+    ]),
+    Scalar([
+        15151953165320026337,
+        13526538600363274505,
+        6571390244100346259,
+        4437221930212480001,
+    ]),
+    Scalar([
+        2914985164023899110,
+        8599568909965291287,
+        1542695343878342016,
+        196953138560888999,
+    ]),
+    Scalar([
+        12923458962650364453,
+        3343796771810236125,
+        3408509369744130875,
+        7905156499906550686,
+    ]),
+    Scalar([
+        230496036790330079,
+        10083176341232501832,
+        17820428318604894048,
+        5853798892716316842,
+    ]),
+    Scalar([
+        10973017218212349316,
+        13363429950936296827,
+        11795474203664507942,
+        6690998621320519350,
+    ]),
+    Scalar([
+        14933250432583398119,
+        3699588745020875150,
+        1904902287636979241,
+        7301486756429102818,
+    ]),
+    Scalar([
+        14326610781214411277,
+        3619119142139860688,
+        12140452723135035325,
+        4718693923045520558,
+    ]),
+    Scalar([
+        8409654343440925372,
+        17859125570345155611,
+        11773582734178758588,
+        4815276292153970935,
+    ]),
+    Scalar([
+        4151135099630438909,
+        9842370447484120616,
+        4647450502554013937,
+        4632877089100939699,
+    ]),
+    Scalar([
+        14291861907646183228,
+        9450541314206617670,
+        2755890486894706351,
+        6883871503893754056,
+    ]),
+    Scalar([
+        1301744656902461101,
+        6990534074517921837,
+        11266004701462593301,
+        2057237728489873511,
+    ]),
+    Scalar([
+        9875658656786775738,
+        3032986240678666212,
+        4417448691979239025,
+        5446007973911660144,
+    ]),
+    Scalar([
+        4675691682918732283,
+        1498827687073840564,
+        1388382254357896871,
+        3042962574704386846,
+    ]),
+    Scalar([
+        11345158661048134272,
+        833917769188136284,
+        18271185869249243499,
+        7405515058022779378,
+    ]),
+    Scalar([
+        9513111354991490995,
+        16352137644203127118,
+        16828143313179040373,
+        507759998979883171,
+    ]),
+    Scalar([
+        575424700770807178,
+        2432053927986873377,
+        14353534407692618863,
+        2406195425956184189,
+    ]),
+    Scalar([
+        9056088529293847409,
+        3078399031989413,
+        1906526627685633702,
+        4989799770520637141,
+    ]),
+    Scalar([
+        11606930513415406654,
+        7803437151239218847,
+        16872042310952540165,
+        6664539116428943880,
+    ]),
+    Scalar([
+        18248884495732338864,
+        2204750970259992859,
+        9330330347255257247,
+        6673139132896088020,
+    ]),
+    Scalar([
+        14682369358692077565,
+        4847831952611523738,
+        13033549506336104943,
+        3376406010890960963,
+    ]),
+    Scalar([
+        1762753363696976550,
+        12540721145904304208,
+        4047966601501052424,
+        2223255979291342664,
+    ]),
+    Scalar([
+        3630015235218079285,
+        15572422919837536020,
+        9926516694674270450,
+        2687197038578762275,
+    ]),
+    Scalar([
+        15537506592705895852,
+        2900841017368585925,
+        18364138587765096804,
+        5433291513330075829,
+    ]),
+    Scalar([
+        2058204027349927234,
+        5199879425817371414,
+        3989373346093721711,
+        1854128903503287505,
+    ]),
+    Scalar([
+        11444469521625338260,
+        13801333585991062617,
+        2751611258216270725,
+        5265233542431976282,
+    ]),
+    Scalar([
+        9346659587988613405,
+        14563201555938954409,
+        18316130818757146812,
+        6506962229601890464,
+    ]),
+    Scalar([
+        11543428271190209707,
+        6184246229913003573,
+        7289283262007065787,
+        8014958750350366005,
+    ]),
+    Scalar([
+        18408919089747495222,
+        13582781838246323155,
+        725181611310438092,
+        7533754482958125233,
+    ]),
+    Scalar([
+        17269039527086052504,
+        15815993529147681978,
+        15126855432493370737,
+        6928787720181003018,
+    ]),
+    Scalar([
+        15481255870199348401,
+        17568142781319502934,
+        3770938364197488652,
+        4071146391940093870,
+    ]),
+    Scalar([
+        16401618259047934910,
+        16148292730575709249,
+        1819168152133572692,
+        7230050787306358264,
+    ]),
+    Scalar([
+        9262848543954679519,
+        14528311267175782862,
+        14377350772454057163,
+        5361327983886261777,
+    ]),
+    Scalar([
+        12403470897580179266,
+        12617007557702430800,
+        2619488814113417800,
+        5946139284279921701,
+    ]),
+    Scalar([
+        16366755865690681620,
+        9484278745295408924,
+        207393599810893859,
+        5444001776250954544,
+    ]),
+    Scalar([
+        13407462792702154221,
+        1409396222244735568,
+        15499916762189711994,
+        5070584290263109094,
+    ]),
+    Scalar([
+        10659258934773505705,
+        11683212566895996072,
+        11739214406057882376,
+        4832195760166366003,
+    ]),
+    Scalar([
+        5750660654856887016,
+        13128424769207087575,
+        2250075828753532075,
+        8169957027066471314,
+    ]),
+    Scalar([
+        5528463127411715431,
+        14475012508271900827,
+        15032579816626834824,
+        2387082320209173376,
+    ]),
+    Scalar([
+        17689146998545864849,
+        10409155290931300798,
+        6029233011927786386,
+        8041043283491284963,
+    ]),
+    Scalar([
+        5805690079272781997,
+        220726369945761463,
+        15176525806953786932,
+        4783742296592348682,
+    ]),
+    Scalar([
+        5223847285189950155,
+        13097713736801560211,
+        11880835162362229341,
+        5604499641184110443,
+    ]),
+    Scalar([
+        2930290525808556328,
+        2249670985844665231,
+        4406254610503946797,
+        2756907575473593855,
+    ]),
+    Scalar([
+        3399116111084795211,
+        2900298443598389785,
+        5410223726670156160,
+        6150312569165652965,
+    ]),
+    Scalar([
+        5827318412392314337,
+        6113943616364275891,
+        1908310422509010988,
+        5639793559034307661,
+    ]),
+    Scalar([
+        1860033239168498131,
+        11965433371736111080,
+        11384471545894381864,
+        8129861600380752881,
+    ]),
+    Scalar([
+        11239550507786264389,
+        12292016112029526896,
+        12726017271749549946,
+        2937298684023417288,
+    ]),
+    Scalar([
+        8620595873512262277,
+        13459317904910789901,
+        7187027573442582558,
+        3026681114167007635,
+    ]),
+    Scalar([
+        14191687423996807217,
+        8587426775415732131,
+        4473767015810721537,
+        3713751340984167878,
+    ]),
+    Scalar([
+        8745755028595180443,
+        13275327006353326692,
+        15937275955107629728,
+        6541626102159666705,
+    ]),
+    Scalar([
+        5614460873382787589,
+        6845577940467547301,
+        2225170593512575353,
+        5667168200392650094,
+    ]),
+    Scalar([
+        509551229123085327,
+        6659462942843864616,
+        14758371409745745717,
+        8032719783090341779,
+    ]),
+    Scalar([
+        3881835314683757284,
+        12296243984271283487,
+        17345092362772278457,
+        5370641236476114056,
+    ]),
+    Scalar([
+        8423055246040128131,
+        3298001935048021717,
+        4230048161137902657,
+        3906181727493343629,
+    ]),
+    Scalar([
+        15723225357433154546,
+        1747052979891725804,
+        17755936823957501597,
+        3577608741478608505,
+    ]),
+    Scalar([
+        14140549732935943810,
+        16911685025713504972,
+        13825172189068831138,
+        3248564619931378888,
+    ]),
+    Scalar([
+        15452222455418585846,
+        7619375283633990858,
+        4857952490926902204,
+        1540951259669444530,
+    ]),
+    Scalar([
+        10097942599707124331,
+        6185072576320116036,
+        1887576437292741128,
+        894777097961658111,
+    ]),
+    Scalar([
+        2980558231014447557,
+        10619698565879998983,
+        416006166641774198,
+        3115898188217658663,
+    ]),
+    Scalar([
+        15730975506603041048,
+        15962676720972694747,
+        16738150499676722317,
+        6649127005317189139,
+    ]),
+    Scalar([
+        11719606383944271570,
+        9166285748567426154,
+        1871448522622887750,
+        8157585344923244360,
+    ]),
+    Scalar([
+        18033858593367177667,
+        13747063711439473415,
+        2118487063799115482,
+        2108615692286944541,
+    ]),
+    Scalar([
+        9231506381713170512,
+        17125371947151461159,
+        7047691978275731621,
+        4563106498856422469,
+    ]),
+    Scalar([
+        13074575076751847973,
+        8422503720886489132,
+        9330954391026332003,
+        7476244298295593590,
+    ]),
+    Scalar([
+        14147819039508726533,
+        4818196966381479712,
+        1477739027121600481,
+        4341198431825531003,
+    ]),
+    Scalar([
+        15879342870827028652,
+        16785969440831105527,
+        5970217314194533541,
+        5907373231814647900,
+    ]),
+    Scalar([
+        16095848161084506096,
+        14945429761150295388,
+        10590384880657625134,
+        2396849608252686839,
+    ]),
+    Scalar([
+        4655029605128529319,
+        13720321567288017521,
+        17428392379269694291,
+        6155778230402046224,
+    ]),
+    Scalar([
+        2229148095773390706,
+        7306631155270776055,
+        12424413947462416924,
+        6814219368846629806,
+    ]),
+    Scalar([
+        16524010722924583570,
+        791720137626247062,
+        14589442836555537539,
+        8164033927482766936,
+    ]),
+    Scalar([
+        12191301675318488560,
+        2591866908088156875,
+        16947632185944107638,
+        5233090654326067267,
+    ]),
+    Scalar([
+        3365202519651283650,
+        8895345381007861025,
+        3567336064126523208,
+        6597792945177966527,
+    ]),
+    Scalar([
+        9349456405048718144,
+        255559186435782051,
+        3303457707982152966,
+        2872298524261696937,
+    ]),
+    Scalar([
+        5519450553604030831,
+        3130496822870265691,
+        7145220799870251637,
+        6909863074143852576,
+    ]),
+    Scalar([
+        12857375141650905471,
+        10316697904752875151,
+        4006717353736718307,
+        3930857826768938875,
+    ]),
+    Scalar([
+        14399650884340202096,
+        10861006106830639211,
+        4618211973123023902,
+        6464728433144343936,
+    ]),
+    Scalar([
+        5811577229049425111,
+        11568358092362768558,
+        12839960036318094142,
+        1486399909737169438,
+    ]),
+    Scalar([
+        8807414793259830662,
+        10897553242881123598,
+        4299120732876793214,
+        3797691759025380837,
+    ]),
+    Scalar([
+        13596670814115293114,
+        15077296871557843961,
+        9831833687126524805,
+        2932509103774473293,
+    ]),
+    Scalar([
+        7033462478506147298,
+        14497135144489795061,
+        6974185963782727576,
+        6509785570784239835,
+    ]),
+    Scalar([
+        10235183993139744252,
+        15585136121116786342,
+        2419587743732598463,
+        6953600360507440583,
+    ]),
+    Scalar([
+        3375961782547818940,
+        12729536564386686778,
+        8911156274602972281,
+        5634295606466271820,
+    ]),
+    Scalar([
+        514900683970904216,
+        16960099750839481356,
+        16174159129975842938,
+        6408568305949357843,
+    ]),
+    Scalar([
+        8006029735001899983,
+        12427398832930819965,
+        10905829546244679077,
+        2510662948199638674,
+    ]),
+    Scalar([
+        2801165834530710431,
+        5965462543448648912,
+        13975686628714828567,
+        5636594085700193497,
+    ]),
+    Scalar([
+        1480458553988778088,
+        16881145454551253358,
+        10141533391099619174,
+        4749182556874515347,
+    ]),
+    Scalar([
+        10501168606344348230,
+        10561311656037881497,
+        3685416487149669075,
+        3184975261201268853,
+    ]),
+    Scalar([
+        1887823470597459330,
+        7351384682238219317,
+        1319336237524101847,
+        775824290114011623,
+    ]),
+    Scalar([
+        9343090049344815429,
+        17007603149965794986,
+        12842333797185644128,
+        2730799960916800936,
+    ]),
+    Scalar([
+        12203418212672247758,
+        7616434150612721975,
+        17433865787950130403,
+        6496885922760668960,
+    ]),
+    Scalar([
+        4753779717778541054,
+        189745871815868732,
+        8032248324039763646,
+        2052312318744182339,
+    ]),
+    Scalar([
+        7510612676207860445,
+        1226285561768743381,
+        3677290570640226248,
+        3607614146271056511,
+    ]),
+    Scalar([
+        12197481907662061253,
+        16101573333841486874,
+        17946815910851736554,
+        7168377180397892575,
+    ]),
+    Scalar([
+        15611908681653449694,
+        3973838778064642868,
+        6395321420490076323,
+        8228452625171291067,
+    ]),
+    Scalar([
+        4055637699041580025,
+        6669865854674587555,
+        14247944150059171511,
+        5452398824562924136,
+    ]),
+    Scalar([
+        18100176204277131574,
+        16540539846134601796,
+        5237466967013687161,
+        4283190187272368061,
+    ]),
+    Scalar([
+        9333340180388994008,
+        18011945369189761973,
+        7127764514194425242,
+        7057013360536902258,
+    ]),
+    Scalar([
+        9700259658546757134,
+        16735714965599519003,
+        1302893537818173577,
+        516156377700852553,
+    ]),
+    Scalar([
+        7494508636822691064,
+        5905093600035012693,
+        17346229643172895661,
+        1660120968718635901,
+    ]),
+    Scalar([
+        4187029039740337434,
+        7666933991147140342,
+        14431353644596920809,
+        7092202749730700,
+    ]),
+    Scalar([
+        411740659466588188,
+        2281908789970474030,
+        16856884350244637195,
+        1057879932166803768,
+    ]),
+    Scalar([
+        16877170108774963536,
+        6481876452835509047,
+        2823199964895659823,
+        7828462260524503214,
+    ]),
+    Scalar([
+        3621090072460936337,
+        12210471004884719438,
+        1738858097219182609,
+        8280011979671617479,
+    ]),
+    Scalar([
+        12814810433888343781,
+        1837606850680642708,
+        13135364628774192429,
+        7076320512828160050,
+    ]),
+    Scalar([
+        17083795624597434962,
+        16079609921299322735,
+        6981179627101236207,
+        5075308069444496334,
+    ]),
+    Scalar([
+        11433652934785076198,
+        16295793509944065550,
+        10902809628354366741,
+        3649798985932958285,
+    ]),
+    Scalar([
+        499419571392117751,
+        16724628780062784540,
+        9253550915469301506,
+        529629411069277010,
+    ]),
+    Scalar([
+        16575218779780980437,
+        2542565522306778196,
+        654786414087883983,
+        8085631998955191155,
+    ]),
+    Scalar([
+        3939083321380674075,
+        5387156492022776079,
+        2906801309855108374,
+        6568422383542199785,
+    ]),
+    Scalar([
+        17247072857968109185,
+        6547258047759372816,
+        17230186941444640183,
+        2303175159281751332,
+    ]),
+    Scalar([
+        12507526507870909627,
+        3573035552755530853,
+        6745969690875663899,
+        204129396705266481,
+    ]),
+    Scalar([
+        5773632231803749800,
+        2444868304445768294,
+        16826847828421160331,
+        6512436199514641385,
+    ]),
+    Scalar([
+        1128028434594208987,
+        18057338890405283103,
+        6586853427976807980,
+        6917260611168606586,
+    ]),
+    Scalar([
+        11413556298914270587,
+        10228195243408158214,
+        239055052214527019,
+        3025320945434317785,
+    ]),
+    Scalar([
+        942357628294351110,
+        4354489064083104904,
+        561344989062885312,
+        4094783303095305827,
+    ]),
+    Scalar([
+        8876907765189980265,
+        5473593511122189241,
+        1796535460609683103,
+        2059121931616772589,
+    ]),
+    Scalar([
+        14977008326774065181,
+        16584340078376639183,
+        16153042128639842901,
+        3095175919501282577,
+    ]),
+    Scalar([
+        8925118232455698259,
+        2751748171087085290,
+        3096969902589231051,
+        8193302536342916382,
+    ]),
+    Scalar([
+        14680398097994731739,
+        2114205679266346289,
+        6736073930265663791,
+        8036708708371390089,
+    ]),
+    Scalar([
+        16474440297620781759,
+        3789263250388053399,
+        2490722063005941900,
+        4994258026181528745,
+    ]),
+    Scalar([
+        16935752981977938759,
+        17491357726731602526,
+        2353369490225324289,
+        7313038226630138587,
+    ]),
+    Scalar([
+        13826450320898328986,
+        15350348303660978437,
+        1450642614381388251,
+        5783012098133359174,
+    ]),
+    Scalar([
+        3898621691000948569,
+        1183310526573635507,
+        11456603326967010132,
+        552826355121650439,
+    ]),
+    Scalar([
+        16359049266822900726,
+        7244621293327944904,
+        4789564337035960863,
+        7159271031324099144,
+    ]),
+    Scalar([
+        11175294204925282455,
+        7899412455017570234,
+        6448532814307195951,
+        1421551146726693529,
+    ]),
+    Scalar([
+        7407329115290582248,
+        9386029123977809593,
+        2823420042943734434,
+        1365942434522653445,
+    ]),
+    Scalar([
+        15261469578865960248,
+        15082159964820627131,
+        17613885047347529867,
+        7471840658627836489,
+    ]),
+    Scalar([
+        10379785882239813697,
+        9741279308049712434,
+        15260529301470983738,
+        4180087298768355548,
+    ]),
+    Scalar([
+        15932072087125188403,
+        10467698406676668477,
+        7529243170648322600,
+        7409715974954327784,
+    ]),
+    Scalar([
+        13464297120563036765,
+        15975490765397071771,
+        5373499823091656811,
+        3589858633103709841,
+    ]),
+    Scalar([
+        3148522077343453912,
+        14780697733052620871,
+        10905290440937868953,
+        7454238983740959646,
+    ]),
+    Scalar([
+        4007199261059094702,
+        15712080392414336412,
+        13946270808146414933,
+        2316132980525289082,
+    ]),
+    Scalar([
+        12610285343529005979,
+        17184401544657169311,
+        12233619678019737177,
+        5043923299447246473,
+    ]),
+    Scalar([
+        14695283327302402800,
+        17309258346259611981,
+        8627332694884670990,
+        7564894598489566010,
+    ]),
+    Scalar([
+        11613218243455256894,
+        6188194829772637924,
+        7564762763559993841,
+        5513990288199938613,
+    ]),
+    Scalar([
+        2532191403652134103,
+        4386347204858441805,
+        9476300443821556709,
+        344113306301934637,
+    ]),
+    Scalar([
+        724092535011678686,
+        1326075537931344902,
+        8540207234752933002,
+        4702561012107029505,
+    ]),
+    Scalar([
+        5585712852262934084,
+        588117773955217354,
+        5150685291876326552,
+        333712468784609922,
+    ]),
+    Scalar([
+        11889717590813219680,
+        3387167556477984497,
+        14791058725962723090,
+        7968050598380412573,
+    ]),
+    Scalar([
+        3430080833739197124,
+        15645070556817708387,
+        635128666587488624,
+        532061797738535603,
+    ]),
+    Scalar([
+        7256746631628458302,
+        7266707362828630778,
+        14611517750615034256,
+        3332713897612707440,
+    ]),
+    Scalar([
+        14042481290057997580,
+        12313799274469911127,
+        10236258836629615221,
+        5266663741885883455,
+    ]),
+    Scalar([
+        3907826865138085191,
+        2771102208247169019,
+        8837875206280539342,
+        3028328309602708913,
+    ]),
+    Scalar([
+        10441307836833734812,
+        9962455359011818025,
+        11070451355233288755,
+        6448040649010668917,
+    ]),
+    Scalar([
+        7378018538701776103,
+        13650294604492703374,
+        7502895801545619762,
+        4140972626034856584,
+    ]),
+    Scalar([
+        14026799336068175189,
+        14364538941117220895,
+        3973162386737956787,
+        7748074020108664247,
+    ]),
+    Scalar([
+        2935503819501844369,
+        16315031757753878299,
+        17347544783065647005,
+        6647648333229478811,
+    ]),
+    Scalar([
+        5985916305243737107,
+        16224884565965117121,
+        6579457600995630891,
+        5917327233349636358,
+    ]),
+    Scalar([
+        9306807386579559040,
+        12414046748299454058,
+        819668479311642428,
+        4113044619389213712,
+    ]),
+    Scalar([
+        4883583978958140933,
+        6815215761442518864,
+        3510210202739634859,
+        7996890796088177822,
+    ]),
+    Scalar([
+        58610230568300587,
+        9399290931830041259,
+        7725277403740267441,
+        6969875330887384150,
+    ]),
+    Scalar([
+        4224847641703919530,
+        9895253290200504780,
+        6862290933098056563,
+        2473240557061846617,
+    ]),
+    Scalar([
+        955786501093299887,
+        8237838498489751550,
+        6060585388329765947,
+        682897967312461694,
+    ]),
+    Scalar([
+        9935590792312242439,
+        15359548597567371584,
+        6133718838766015222,
+        7507658963745526734,
+    ]),
+    Scalar([
+        4392431515586271852,
+        9406732849807800708,
+        12754976490544328154,
+        4575623151878135605,
+    ]),
+    Scalar([
+        11326689131191475332,
+        10974049770394509290,
+        16454087818407911643,
+        2915716465941576601,
+    ]),
+    Scalar([
+        9304914871264919557,
+        4536639587005295657,
+        6675568564560802275,
+        404495968928368508,
+    ]),
+    Scalar([
+        6430957608420502432,
+        17140258176814000559,
+        14352004220971705447,
+        6005328344939898447,
+    ]),
+    Scalar([
+        13607223634052000521,
+        12659017207973695107,
+        2354112311274571130,
+        3729906072237548513,
+    ]),
+    Scalar([
+        16688349092405377485,
+        7050242279112028021,
+        573797345734400188,
+        3870087524821797185,
+    ]),
+    Scalar([
+        7285246557402957023,
+        5359341886347303680,
+        7932327008151849005,
+        5767093102772539943,
+    ]),
+    Scalar([
+        2132218537235868906,
+        11472515868168309914,
+        3833608711587654794,
+        8154995132544722722,
+    ]),
+    Scalar([
+        8895530298511404177,
+        5657102116560021441,
+        6637508443052736497,
+        1602097790817760489,
+    ]),
+    Scalar([
+        12056036584126595013,
+        7338666906430884342,
+        17347120442480286390,
+        316658010532379577,
+    ]),
+    Scalar([
+        9198225067204218687,
+        11882195274216080466,
+        3127031579355248209,
+        2635746965522932403,
+    ]),
+    Scalar([
+        369049451609882757,
+        3077604438838188624,
+        11933455158916436574,
+        6998535980700248976,
+    ]),
+    Scalar([
+        7299200986585829289,
+        4175999570304628076,
+        2429001334480129409,
+        2209443171261578475,
+    ]),
+    Scalar([
+        15898692454384480786,
+        3481720716325276982,
+        8850790185704064674,
+        5820709298164842181,
+    ]),
+    Scalar([
+        3610210559266346967,
+        17263259866388550718,
+        9948364317175914048,
+        4502882949191535721,
+    ]),
+    Scalar([
+        16884951197883423929,
+        11288764375734624563,
+        15039953875274670214,
+        522380826304607053,
+    ]),
+    Scalar([
+        8955332156893452470,
+        9290830075499828889,
+        6322728843205550919,
+        140378771146722474,
+    ]),
+    Scalar([
+        4872733816089206969,
+        8380052802318206437,
+        3294274867247311223,
+        2335738454405928283,
+    ]),
+    Scalar([
+        6505440003040280622,
+        10843309321535282497,
+        8468241070266018632,
+        3814424068167646045,
+    ]),
+    Scalar([
+        14148972605470403168,
+        7684393390274941256,
+        4411865381690361789,
+        1731154700578536729,
+    ]),
+    Scalar([
+        74539232599849712,
+        8534627561353497286,
+        11404278796249272925,
+        1533941087345961165,
+    ]),
+    Scalar([
+        17671045063962707458,
+        8738588126738941497,
+        3891938747212854645,
+        6552423020690304628,
+    ]),
+    Scalar([
+        14046524302274061033,
+        4363665667886988610,
+        14625586050661450084,
+        8326770277227225071,
+    ]),
+    Scalar([
+        8424983710443730436,
+        17726498501927533692,
+        9241060325252903025,
+        6972842550852974030,
+    ]),
+    Scalar([
+        2256689200269159360,
+        14303845499736659952,
+        16669956382427472535,
+        7214658810154037806,
+    ]),
+    Scalar([
+        7886941951405501232,
+        11679788598307460435,
+        17947403999863464925,
+        2723107713299918523,
+    ]),
+    Scalar([
+        309631344386366605,
+        13784172867633320875,
+        10148487580963954453,
+        4202127276263660012,
+    ]),
+    Scalar([
+        7785013338846296061,
+        2539067698469522451,
+        2648625204891285494,
+        4176438868376995115,
+    ]),
+    Scalar([
+        992084388728719592,
+        5212727261717596997,
+        15623058347798157170,
+        37307522084954435,
+    ]),
+    Scalar([
+        13834195622853329036,
+        14175065653306228783,
+        11603218772851287808,
+        1121086905374553964,
+    ]),
+    Scalar([
+        1275234613957046407,
+        8216527938693935461,
+        4363413094615458949,
+        5786982415916555076,
+    ]),
+    Scalar([
+        13823420348408196346,
+        4493205368880056379,
+        8651759490713724980,
+        3139230268345582494,
+    ]),
+    Scalar([
+        9811998180905119848,
+        1592077592316232060,
+        2142876509645624140,
+        348841386622803455,
+    ]),
+    Scalar([
+        2373648674038472428,
+        13259293578923986776,
+        15452759416149961648,
+        6876584474754951423,
+    ]),
+    Scalar([
+        8607863016589125012,
+        6783209812814288449,
+        16668676283103806964,
+        7425475178676485679,
+    ]),
+    Scalar([
+        849996055062858501,
+        4863062547070343787,
+        4297523934556596799,
+        4255399686885181498,
+    ]),
+    Scalar([
+        2625901529289874127,
+        11414579732681114275,
+        14142898391773113515,
+        3515482387444475598,
+    ]),
+    Scalar([
+        16592924476393149018,
+        8171452932398614737,
+        16446012405383611526,
+        4612977091519084440,
+    ]),
+    Scalar([
+        907784502654825326,
+        17351097296560965267,
+        6687856741671352173,
+        7647594770708395737,
+    ]),
+    Scalar([
+        2455262225181368282,
+        3280780402695992941,
+        12715022140908362018,
+        2877866649610046027,
+    ]),
+    Scalar([
+        9703062100057445250,
+        14433901133736967504,
+        9430543554397521862,
+        5394534996743795610,
+    ]),
+    Scalar([
+        9385882954561397300,
+        18105799942897487173,
+        11662822637513601814,
+        7391258005050211235,
+    ]),
+    Scalar([
+        16478667133387211184,
+        13464599582191294187,
+        6876104294313073363,
+        6269856239414031075,
+    ]),
+    Scalar([
+        5518338570517323021,
+        11509616348953564990,
+        12976794572864622648,
+        96101260779634263,
+    ]),
+    Scalar([
+        12272987506681431877,
+        960685548514110266,
+        6436099887672416952,
+        8161795458715548981,
+    ]),
+    Scalar([
+        13661974309154387570,
+        8504619084044630738,
+        3428933486936204821,
+        3672337404733356693,
+    ]),
+    Scalar([
+        9249498170863358953,
+        14049005913579728285,
+        8122861195673435595,
+        991085928022964450,
+    ]),
+    Scalar([
+        3779640057544573986,
+        10352698607470204622,
+        9901570789729240346,
+        2831128914584958710,
+    ]),
+    Scalar([
+        8290444629857691021,
+        16949981352538963519,
+        9048522229201004420,
+        4785321840223815531,
+    ]),
+    Scalar([
+        10871436816865339179,
+        8861221700976066129,
+        3600447032392348376,
+        7599955410313919232,
+    ]),
+    Scalar([
+        6251694230123100882,
+        9996238909037545929,
+        18428018517187370034,
+        46489806323999470,
+    ]),
+    Scalar([
+        16843766845728121958,
+        12249992643831943591,
+        5018584331906481237,
+        5763683091825873601,
+    ]),
+    Scalar([
+        13767844349085646969,
+        10121645178292874127,
+        4815735418835906364,
+        1270369774039814076,
+    ]),
+    Scalar([
+        4756768781399005683,
+        18400932479674688661,
+        1000673617134477050,
+        2697283596358071866,
+    ]),
+    Scalar([
+        6333958081289052262,
+        10878224129457804792,
+        15759645844990614393,
+        407336176609969450,
+    ]),
+    Scalar([
+        13652288508710882050,
+        14141641868051387879,
+        14640886709156226208,
+        7699810734841801949,
+    ]),
+    Scalar([
+        5531431909597811797,
+        8786429049457199288,
+        18368295900937198441,
+        512079231270662809,
+    ]),
+    Scalar([
+        8585082762478846314,
+        8164797768672755544,
+        9202985086793343088,
+        4257099603112352134,
+    ]),
+    Scalar([
+        10536376316727617212,
+        12184378940373380157,
+        13093505905356045182,
+        7937648811959031251,
+    ]),
+    Scalar([
+        13379706487102131974,
+        14052693462943824597,
+        4595756930742370822,
+        241832307285549380,
+    ]),
+    Scalar([
+        7179091094196833529,
+        13408549237621411011,
+        7934091589221712254,
+        3255360981041880630,
+    ]),
+    Scalar([
+        7912519404178240657,
+        2857734153102612784,
+        12041946424182080922,
+        6097875353931652174,
+    ]),
+    Scalar([
+        2427446981485812425,
+        3987119390351893151,
+        3575930398855589646,
+        7668616874101661457,
+    ]),
+    Scalar([
+        16273791934913589537,
+        16153696687250599556,
+        3188959337932872464,
+        3561029400712397247,
+    ]),
+    Scalar([
+        15589344193174856768,
+        1298784492612209239,
+        5539533436422925766,
+        186895747129259414,
+    ]),
+    Scalar([
+        12688328701033943116,
+        17061365456888022629,
+        7199593074433718763,
+        7373198975219166162,
+    ]),
+    Scalar([
+        18346338756807518484,
+        1436812262697553683,
+        9477283188223051708,
+        3850160534778215159,
+    ]),
+    Scalar([
+        14682422903358442062,
+        1687251121314814545,
+        5411206281559832834,
+        1957672166878379067,
+    ]),
+    Scalar([
+        12610587357233079506,
+        13123596228741301884,
+        10746848124560829849,
+        98567568791670032,
+    ]),
+    Scalar([
+        12847669249021191851,
+        18229096784552094564,
+        17671939764745711349,
+        3285635307666777785,
+    ]),
+    Scalar([
+        11669005626356738706,
+        7767907601403975307,
+        13670154248971410050,
+        2999670478533736734,
+    ]),
+    Scalar([
+        15448477304266093564,
+        12555895512144814765,
+        17716772543030943065,
+        7042676853797280779,
+    ]),
+    Scalar([
+        17775996680167305551,
+        9286666986447211436,
+        17000620996153782272,
+        7201873392014576131,
+    ]),
+    Scalar([
+        153545846368278253,
+        194284024893019077,
+        17431777508313744170,
+        2901781036765999185,
+    ]),
+    Scalar([
+        4063490646127799172,
+        17242960081964401684,
+        5589167002450801954,
+        897009860245803525,
+    ]),
+    Scalar([
+        9971275232489917757,
+        14505894665991777838,
+        17324823790485041667,
+        1615984395585106908,
+    ]),
+    Scalar([
+        7016532079059242222,
+        2655088022903631505,
+        3645195125641901311,
+        6661913211142135065,
+    ]),
+    Scalar([
+        5778500765119890788,
+        3319209848185794119,
+        10850341781584335822,
+        4299140796045321733,
+    ]),
+    Scalar([
+        13444617431227169946,
+        16745590607073965521,
+        10821737875890139567,
+        7392375775625193476,
+    ]),
+    Scalar([
+        3859946350311012588,
+        1051148207390426197,
+        15148675354826311048,
+        6439846599071658479,
+    ]),
+    Scalar([
+        2309247222071792852,
+        18002635121020387778,
+        4563358348264463939,
+        1920028829553331215,
+    ]),
+    Scalar([
+        10529643500170856016,
+        8353418664152468573,
+        15323479658079821542,
+        4549418117090924977,
+    ]),
+    Scalar([
+        7160117121333333494,
+        12869133864534572068,
+        17773833224033282168,
+        1657263641084178912,
+    ]),
+    Scalar([
+        9291356007498601894,
+        13945695763709218413,
+        3913927164764474055,
+        2350227389143772862,
+    ]),
+    Scalar([
+        7784528250998600611,
+        12196687406401195239,
+        12066169149546622003,
+        6679210070672217494,
+    ]),
+    Scalar([
+        7958799251334473450,
+        17211777182485308847,
+        2581517922492978094,
+        2367025166419153802,
+    ]),
+    Scalar([
+        4244117629196051052,
+        8417528750814093383,
+        2374630068058236437,
+        4385205026630884251,
+    ]),
+    Scalar([
+        16248062473685344322,
+        8711141522867933255,
+        7264367526961321723,
+        2302778704231986443,
+    ]),
+    Scalar([
+        9281090350472436579,
+        9372107862967311014,
+        6502174059288124671,
+        7257999439804466050,
+    ]),
+    Scalar([
+        14376329087010060858,
+        8678857203068462889,
+        4091842185947510552,
+        2903395157984404179,
+    ]),
+    Scalar([
+        14053653708482587008,
+        1362349893983630858,
+        12707817918858692859,
+        2671018128757182504,
+    ]),
+    Scalar([
+        15489445857636997376,
+        8858762128275670483,
+        17574286179689017735,
+        7451719891382741402,
+    ]),
+    Scalar([
+        11999598266626665887,
+        15089066811126397509,
+        497683462540966404,
+        3460207361605337022,
+    ]),
+    Scalar([
+        7193504144229120142,
+        2844842667690607242,
+        14495692891335835017,
+        2090292453977069210,
+    ]),
+    Scalar([
+        4411441905820450993,
+        2755985888775936127,
+        1107803100142291826,
+        6132342792711779963,
+    ]),
+    Scalar([
+        15660674945644174876,
+        12010145110117878547,
+        121147640209747734,
+        1157197980224502534,
+    ]),
+    Scalar([
+        13284275203912967587,
+        13360058334999703844,
+        5267346692545318934,
+        6176007731148562204,
+    ]),
+    Scalar([
+        18087835447441403977,
+        12843615935549817781,
+        16128682044151282788,
+        5564377930833052046,
+    ]),
+    Scalar([
+        15513644228230052161,
+        547803787337733895,
+        14890952298350704703,
+        5481032597444710024,
+    ]),
+    Scalar([
+        12646744528142940344,
+        1272791812323740554,
+        16806443654456072624,
+        2689005804656033589,
+    ]),
+    Scalar([
+        13630636057695691199,
+        3324151632647250156,
+        7942052310393800356,
+        7420154826222407520,
+    ]),
+    Scalar([
+        11817169003730712320,
+        5983643240727252450,
+        6177336289293986277,
+        3076979166484714101,
+    ]),
+    Scalar([
+        7571624144506893961,
+        18440173851418951090,
+        11680725111561764370,
+        1376680380782833114,
+    ]),
+    Scalar([
+        16161580070686452397,
+        13964653429592997445,
+        2035074278546549175,
+        7149894491766702457,
+    ]),
+    Scalar([
+        17090212500511568255,
+        7069338804854434952,
+        2937208071399138082,
+        3391157400616878269,
+    ]),
+    Scalar([
+        3618692549572831242,
+        12099686641536529638,
+        14767713130481979978,
+        3174371776566951258,
+    ]),
+    Scalar([
+        2925280627024179782,
+        11664219905282865462,
+        6802784514581799234,
+        6967792320045679809,
+    ]),
+    Scalar([
+        11276199570304610609,
+        7551929110791441692,
+        8554120383516629191,
+        3645864600690488109,
+    ]),
+    Scalar([
+        14141323821590577986,
+        461810622658055673,
+        2568184533287414276,
+        40506977365003737,
+    ]),
+    Scalar([
+        2280705001197948868,
+        2544605860669243830,
+        1558177465916266387,
+        5622825497602741901,
+    ]),
+    Scalar([
+        1487317909313367239,
+        13626735295627197087,
+        13280216339453486850,
+        3689256378818542388,
+    ]),
+    Scalar([
+        14797164916024511201,
+        216642309702498006,
+        15699824054339734461,
+        1497632767078440104,
+    ]),
+    Scalar([
+        2061979452098356873,
+        4281957344578867487,
+        6934164440844064708,
+        2324176373412055004,
+    ]),
+    Scalar([
+        8710565252569504670,
+        5525669234789943230,
+        12262611935357180662,
+        5158776796087260735,
+    ]),
+    Scalar([
+        17705631217912520961,
+        2184266062554732534,
+        1958474141675278943,
+        7780190288730307831,
+    ]),
+    Scalar([
+        4452390455449515655,
+        9235817646426527913,
+        8011003873417721024,
+        3272039392654636052,
+    ]),
+    Scalar([
+        15215230599727856349,
+        16877669987910812928,
+        9174819482918320088,
+        8180643719705563115,
+    ]),
+    Scalar([
+        13129130758882166080,
+        3051904191241932252,
+        10701056233441971585,
+        5094220138355751903,
+    ]),
+    Scalar([
+        5495329660583377582,
+        5762989694374800157,
+        9181808646909057066,
+        3654150579851986838,
+    ]),
+    Scalar([
+        8481354274795284576,
+        10546284419942189592,
+        15807187478915163273,
+        6986364684964917198,
+    ]),
+    Scalar([
+        7386179029708462456,
+        8841792071891965166,
+        10537994761748926262,
+        4384859456861076206,
+    ]),
+    Scalar([
+        363682823315478152,
+        7151521652139140179,
+        611497061972925816,
+        403423862340505194,
+    ]),
+    Scalar([
+        5785263663694537035,
+        13340637864792636241,
+        11679325879513892049,
+        7065770598565953637,
+    ]),
+    Scalar([
+        173067200177629580,
+        15797699468024041416,
+        5791064331838736242,
+        1410333404642098015,
+    ]),
+    Scalar([
+        2860587931752581515,
+        1968536728018849241,
+        10248429264096740786,
+        7939972313343064174,
+    ]),
+    Scalar([
+        12780493819130325055,
+        17466200126998253974,
+        1333271041559727659,
+        6537399575795043637,
+    ]),
+    Scalar([
+        13289391615585836031,
+        6165848701933452116,
+        4034984918429806294,
+        3386141241257310526,
+    ]),
+    Scalar([
+        2174253992049097214,
+        12411220389506342908,
+        13872267385275359265,
+        1983022290491839815,
+    ]),
+    Scalar([
+        198950775104566911,
+        8822163107763832969,
+        17011524709869077244,
+        3718075590225247756,
+    ]),
+    Scalar([
+        3619373900199578099,
+        4448936135283292373,
+        5102354311671458094,
+        5479220010320647113,
+    ]),
+    Scalar([
+        18281248906948484088,
+        6719682162561594122,
+        15098627765160393324,
+        7880332358491801138,
+    ]),
+    Scalar([
+        3999450689228515524,
+        12614448845035000487,
+        6955994580893491572,
+        1313366508508308697,
+    ]),
+    Scalar([
+        7371840398542731317,
+        7134811350584668418,
+        14229342690232460668,
+        1426900197724576904,
+    ]),
+    Scalar([
+        16229965148061335564,
+        919028533636878225,
+        13717659853507551031,
+        2445021038926202736,
+    ]),
+    Scalar([
+        3685709711716590220,
+        2364748561259274703,
+        14638665919076619738,
+        8203699274295554719,
+    ]),
+    Scalar([
+        9408788710317912926,
+        5270806549129479985,
+        7423183485610973404,
+        5399103054778593817,
+    ]),
+    Scalar([
+        13728688001292418188,
+        12442384594941832882,
+        9158621264828490616,
+        5814289282563498655,
+    ]),
+    Scalar([
+        13757957529549859943,
+        12525513568452252072,
+        1900721546511026854,
+        2472370567965395974,
+    ]),
+    Scalar([
+        8578067504181069360,
+        7142713777262627430,
+        2720398329616650474,
+        3608035846522391456,
+    ]),
+    Scalar([
+        12408369914295213944,
+        11846720004076542531,
+        9870356693937920286,
+        2531945439543137459,
+    ]),
+    Scalar([
+        7747023621493447576,
+        2296164289997942754,
+        6606600617048732267,
+        847405031328580363,
+    ]),
+    Scalar([
+        11587700537330184876,
+        16960211021274313532,
+        8654514391305811954,
+        1542142485170018508,
+    ]),
+    Scalar([
+        17933598226709781188,
+        7416277667412410711,
+        3717029740926917626,
+        4398506052374452192,
+    ]),
+    Scalar([
+        7850204275502727450,
+        13939263634550964155,
+        16007920953311919069,
+        1026526088448299443,
+    ]),
+    Scalar([
+        15401417319895500918,
+        10240076958846825818,
+        9954282119921457967,
+        7598429439422208802,
+    ]),
+    Scalar([
+        1801928700515912687,
+        15135475537495870432,
+        8619455076398629155,
+        7387606867011419840,
+    ]),
+    Scalar([
+        7999981712513511425,
+        15972431910974895438,
+        18377567968843372722,
+        575051968606189327,
+    ]),
+    Scalar([
+        12991690595887564573,
+        13245043441916614158,
+        6940462233093833495,
+        4595278182294385134,
+    ]),
+    Scalar([
+        6263260531433721241,
+        7978457712886496842,
+        6946416603734224368,
+        6625098236978037107,
+    ]),
+    Scalar([
+        125757914459092116,
+        12489170574463000628,
+        8927208389622400150,
+        8143410895568244588,
+    ]),
+    Scalar([
+        3584289293145436780,
+        14389942268543343497,
+        10705220712786263495,
+        5650994757943241474,
+    ]),
+    Scalar([
+        6541992792225603359,
+        404596048321285311,
+        12750567494136966679,
+        7936958962622164104,
+    ]),
+    Scalar([
+        9917378373418406339,
+        14883337304531765844,
+        17424511461999015805,
+        2827177371794251602,
+    ]),
+    Scalar([
+        8803834414208498057,
+        3860347385920979369,
+        7666582477472072147,
+        3717612548133471649,
+    ]),
+    Scalar([
+        5526301823884022057,
+        5561436973211190483,
+        15066434838031294330,
+        5914965460622818663,
+    ]),
+    Scalar([
+        10498631252802893417,
+        16133701314330348910,
+        15160375290536959702,
+        3767770401406777835,
+    ]),
+    Scalar([
+        2012065563439579432,
+        14792220089977018620,
+        147616203846511072,
+        6580106428145502082,
+    ]),
+    Scalar([
+        16642486946807699971,
+        8783599065869300383,
+        2816896544803027150,
+        6953390525118086224,
+    ]),
+    Scalar([
+        16641352776330492837,
+        1628467213858307876,
+        11032209148689659598,
+        3917676138855013129,
+    ]),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5324,4 +9548,20 @@ mod tests {
             assert_eq!(Scalar::from_raw(product.0), Scalar::one());
         }
     }
+
+    #[test]
+    fn test_sbox_polynomial() {
+        (0..703u64).for_each(|x| {
+            let x_scalar = Scalar::from(x);
+            let value = SBOX_POLYNOMIAL
+                .iter()
+                .rev()
+                .fold(Scalar::zero(), |acc, c| acc * x_scalar + c);
+            let expected = match x < 659 {
+                true => SBOX_MONTGOMERY[x as usize],
+                false => x_scalar,
+            };
+            assert_eq!(value, expected);
+        });
+    }
 }
@@ -0,0 +1,304 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Hash chains `h_{i+1} = Zelbet(h_i, tag)`, where a step is one Zelbet
+//! permutation of `[h_i, tag, chain domain tag]` keeping the first element.
+//! The domain tag in the last element keeps the steps apart from the other
+//! uses of the permutation, such as the compression function of the Merkle
+//! trees, which are separated by their own distinct domain tags.
+//!
+//! Checkpoints are the values of the chain every `interval` steps, which the
+//! gadgets can expose as public inputs, for instance to prove that a number
+//! of sequential steps was performed between two published values.
+
+use crate::hashing::sponge::domain_tag;
+use crate::hashing::zelbet::{zelbet_out_of_circuit, ZelbetConstants};
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Label of the domain of the steps of a chain
+pub const HASH_CHAIN_DOMAIN: &[u8] = b"rc.chain";
+
+/// Apply one step of the chain (out of circuit)
+pub fn hash_chain_step(h: BlsScalar, tag: BlsScalar) -> BlsScalar {
+    zelbet_out_of_circuit([h, tag, domain_tag(HASH_CHAIN_DOMAIN)])[0]
+}
+
+/// Apply `steps` steps of the chain from `start` (out of circuit)
+pub fn hash_chain(start: BlsScalar, tag: BlsScalar, steps: usize) -> BlsScalar {
+    (0..steps).fold(start, |h, _| hash_chain_step(h, tag))
+}
+
+/// Values of the chain after every multiple of `interval` steps, up to
+/// `steps` steps (out of circuit)
+pub fn hash_chain_checkpoints(
+    start: BlsScalar,
+    tag: BlsScalar,
+    steps: usize,
+    interval: usize,
+) -> Vec<BlsScalar> {
+    assert!(interval > 0, "the interval between checkpoints is positive");
+    let mut h = start;
+    (0..steps / interval)
+        .map(|_| {
+            h = hash_chain(h, tag, interval);
+            h
+        })
+        .collect()
+}
+
+fn steps_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    domain: Variable,
+    start: (Variable, BlsScalar),
+    tag: (Variable, BlsScalar),
+    steps: usize,
+) -> Variable {
+    let domain_value = domain_tag(HASH_CHAIN_DOMAIN);
+    let (end, _) = (0..steps).fold(start, |(h, h_value), _| {
+        let next = constants.permute(
+            composer,
+            &[h, tag.0, domain],
+            &[h_value, tag.1, domain_value],
+        )[0];
+        (next, hash_chain_step(h_value, tag.1))
+    });
+    end
+}
+
+/// Apply `steps` steps of the chain from `start` in circuit, returning the
+/// last value. The start and the tag are given as variables along with their
+/// values.
+pub fn hash_chain_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    start: Variable,
+    start_value: BlsScalar,
+    tag: Variable,
+    tag_value: BlsScalar,
+    steps: usize,
+) -> Variable {
+    let domain = composer
+        .add_witness_to_circuit_description(domain_tag(HASH_CHAIN_DOMAIN));
+    steps_gadget(
+        composer,
+        constants,
+        domain,
+        (start, start_value),
+        (tag, tag_value),
+        steps,
+    )
+}
+
+/// Apply `checkpoints.len() * interval` steps of the chain from `start` in
+/// circuit, constraining the value after every `interval` steps to the
+/// corresponding checkpoint, which is a public input. The start and the tag
+/// are given as variables along with their values, and the last value is
+/// returned.
+#[allow(clippy::too_many_arguments)]
+pub fn hash_chain_checkpoints_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    start: Variable,
+    start_value: BlsScalar,
+    tag: Variable,
+    tag_value: BlsScalar,
+    interval: usize,
+    checkpoints: &[BlsScalar],
+) -> Variable {
+    assert!(interval > 0, "the interval between checkpoints is positive");
+    let domain = composer
+        .add_witness_to_circuit_description(domain_tag(HASH_CHAIN_DOMAIN));
+    let (end, _) = checkpoints.iter().fold(
+        (start, start_value),
+        |(h, h_value), checkpoint| {
+            let h = steps_gadget(
+                composer,
+                constants,
+                domain,
+                (h, h_value),
+                (tag, tag_value),
+                interval,
+            );
+            composer.constrain_to_constant(
+                h,
+                BlsScalar::zero(),
+                Some(-*checkpoint),
+            );
+            (h, hash_chain(h_value, tag_value, interval))
+        },
+    );
+    end
+}
+
+/// Number of gates added by [`hash_chain_gadget`] for the given number of
+/// steps, not counting the [`ZelbetConstants`] that are shared with the rest
+/// of the circuit
+pub fn hash_chain_cost(steps: usize) -> usize {
+    let mut composer = StandardComposer::new();
+    let constants = ZelbetConstants::new(&mut composer);
+    let start = composer.add_input(BlsScalar::zero());
+    let tag = composer.add_input(BlsScalar::zero());
+
+    let size_before = composer.circuit_size();
+    hash_chain_gadget(
+        &mut composer,
+        &constants,
+        start,
+        BlsScalar::zero(),
+        tag,
+        BlsScalar::zero(),
+        steps,
+    );
+
+    composer.circuit_size() - size_before
+}
+
+/// Number of gates added by each step of [`hash_chain_gadget`]
+pub fn hash_chain_step_cost() -> usize {
+    hash_chain_cost(1) - hash_chain_cost(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+    use crate::merkle::merkle_compress;
+
+    #[test]
+    fn test_hash_chain_vectors() {
+        let start = BlsScalar::from(7);
+        let tag = BlsScalar::from(3);
+        assert_eq!(
+            hash_chain_step(start, tag),
+            BlsScalar([
+                9588811162583214283,
+                13265582838596966002,
+                9079568649634599180,
+                5792540044396728693,
+            ])
+        );
+        assert_eq!(
+            hash_chain(start, tag, 6),
+            BlsScalar([
+                1112471624773549007,
+                6351918489237802247,
+                5819621140064744693,
+                4418095217311889984,
+            ])
+        );
+        assert_eq!(hash_chain(start, tag, 0), start);
+        assert_ne!(hash_chain_step(start, tag), merkle_compress(start, tag));
+    }
+
+    #[test]
+    fn test_hash_chain_checkpoints() {
+        let start = BlsScalar::from(7);
+        let tag = BlsScalar::from(3);
+        let checkpoints = hash_chain_checkpoints(start, tag, 7, 2);
+        assert_eq!(checkpoints.len(), 3);
+        checkpoints.iter().enumerate().for_each(|(j, checkpoint)| {
+            assert_eq!(*checkpoint, hash_chain(start, tag, 2 * (j + 1)));
+        });
+        assert_eq!(
+            hash_chain_checkpoints(start, tag, 5, 5),
+            vec![BlsScalar([
+                15805958030741485107,
+                9204204066878633245,
+                15715652871848833127,
+                6182383969621685865,
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_hash_chain_cost() {
+        let step = hash_chain_step_cost();
+        assert!(step > 0);
+        assert_eq!(hash_chain_cost(4), hash_chain_cost(0) + 4 * step);
+    }
+
+    #[test]
+    fn test_hash_chain_checkpoints_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let start_scalar = BlsScalar::from(7);
+                let tag_scalar = BlsScalar::from(3);
+                let checkpoints =
+                    hash_chain_checkpoints(start_scalar, tag_scalar, 6, 3);
+
+                let start = composer.add_input(start_scalar);
+                let tag = composer.add_input(tag_scalar);
+                let end = hash_chain_checkpoints_gadget(
+                    composer,
+                    &constants,
+                    start,
+                    start_scalar,
+                    tag,
+                    tag_scalar,
+                    3,
+                    &checkpoints,
+                );
+                let expected = hash_chain_gadget(
+                    composer,
+                    &constants,
+                    start,
+                    start_scalar,
+                    tag,
+                    tag_scalar,
+                    6,
+                );
+                composer.assert_equal(end, expected);
+
+                pad_lookup_gates(composer);
+            },
+            17000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_hash_chain_checkpoints_gadget_wrong_checkpoint_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let start_scalar = BlsScalar::from(7);
+                let tag_scalar = BlsScalar::from(3);
+                let mut checkpoints =
+                    hash_chain_checkpoints(start_scalar, tag_scalar, 6, 3);
+                checkpoints[0] += BlsScalar::one();
+
+                let start = composer.add_input(start_scalar);
+                let tag = composer.add_input(tag_scalar);
+                hash_chain_checkpoints_gadget(
+                    composer,
+                    &constants,
+                    start,
+                    start_scalar,
+                    tag,
+                    tag_scalar,
+                    3,
+                    &checkpoints,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            17000,
+        );
+        assert!(res.is_err());
+    }
+}
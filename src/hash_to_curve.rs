@@ -0,0 +1,359 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Hashing of byte strings to points of the prime order subgroup of Jubjub,
+//! following the random oracle construction of RFC 9380: two field elements
+//! from [`hash_to_field`] are mapped to the curve, the points are added and
+//! the cofactor is cleared.
+//!
+//! The map is Elligator 2 on the Montgomery form `B·v^2 = u^3 + A·u^2 + u`
+//! of Jubjub, with `A = 40962`, `B = -40964` and the non-square `Z = 5`,
+//! followed by the birational map `(x, y) = (u / v, (u - 1) / (u + 1))` to
+//! the twisted Edwards form. Of the two square roots `v`, the even one is
+//! taken. The exceptional points `v = 0` and `u = -1` are sent to the
+//! identity.
+
+use crate::hashing::gadgets::{scalar_bits_gadget, SCALAR_BITS};
+use crate::hashing::hash_to_field::{hash_to_field, hash_to_field_gadget};
+use crate::hashing::zelbet::ZelbetConstants;
+use dusk_bytes::Serializable;
+use dusk_jubjub::{JubJubAffine, JubJubExtended};
+use dusk_plonk::{
+    constraint_system::{ecc::Point, StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Coefficient `A` of the Montgomery form of Jubjub
+pub const MONTGOMERY_A: u64 = 40962;
+
+/// Opposite of the coefficient `B` of the Montgomery form of Jubjub
+pub const MONTGOMERY_MINUS_B: u64 = 40964;
+
+/// Non-square used by the Elligator 2 map
+pub const ELLIGATOR_Z: u64 = 5;
+
+/// Whether the canonical representation of a scalar is odd
+fn is_odd(x: &BlsScalar) -> bool {
+    x.to_bytes()[0] & 1 == 1
+}
+
+/// Right hand side `(u^3 + A·u^2 + u) / B` of the Montgomery form
+fn montgomery_rhs(u: BlsScalar) -> BlsScalar {
+    -u * (u.square() + BlsScalar::from(MONTGOMERY_A) * u + BlsScalar::one())
+        * BlsScalar::from(MONTGOMERY_MINUS_B)
+            .invert()
+            .expect("B is not zero")
+}
+
+/// Values of the Elligator 2 map on a field element: the candidate `x1`,
+/// whether it was kept, and the point `(u, v)` on the Montgomery form
+fn elligator(t: BlsScalar) -> (BlsScalar, bool, BlsScalar, BlsScalar) {
+    let a = BlsScalar::from(MONTGOMERY_A);
+
+    // The denominator never vanishes, as -1 is a square and Z is not
+    let x1 = -a
+        * (BlsScalar::one() + BlsScalar::from(ELLIGATOR_Z) * t.square())
+            .invert()
+            .expect("-1/Z is not a square");
+
+    // Exactly one of g(x1) and g(x2) is a square
+    let (is_x1, u, v) =
+        match Option::<BlsScalar>::from(montgomery_rhs(x1).sqrt()) {
+            Some(v) => (true, x1, v),
+            None => {
+                let x2 = -x1 - a;
+                let v = Option::<BlsScalar>::from(montgomery_rhs(x2).sqrt())
+                    .expect("g(x2) is a square when g(x1) is not");
+                (false, x2, v)
+            }
+        };
+    let v = if is_odd(&v) { -v } else { v };
+
+    (x1, is_x1, u, v)
+}
+
+/// Map a field element to a point of Jubjub, which is not necessarily in the
+/// prime order subgroup
+pub fn map_to_curve(t: BlsScalar) -> JubJubAffine {
+    let (_, _, u, v) = elligator(t);
+    let one = BlsScalar::one();
+
+    match (
+        Option::<BlsScalar>::from(v.invert()),
+        Option::<BlsScalar>::from((u + one).invert()),
+    ) {
+        (Some(v_inv), Some(u_plus_one_inv)) => {
+            JubJubAffine::from_raw_unchecked(
+                u * v_inv,
+                (u - one) * u_plus_one_inv,
+            )
+        }
+        _ => JubJubAffine::identity(),
+    }
+}
+
+/// Hash a message to a point of the prime order subgroup of Jubjub under the
+/// domain separation tag, which must not be empty
+pub fn hash_to_curve(dst: &[u8], msg: &[u8]) -> JubJubExtended {
+    let t = hash_to_field(dst, msg, 2);
+    let q = JubJubExtended::from(map_to_curve(t[0]))
+        + JubJubExtended::from(map_to_curve(t[1]));
+    q.mul_by_cofactor()
+}
+
+/// Map a field element to a point of Jubjub in circuit, where the element is
+/// given as a variable along with its value.
+///
+/// The choice between `x1` and `x2` is a witnessed bit, which can not be
+/// flipped since exactly one of `g(x1)` and `g(x2)` is a square, and the root
+/// `v` is checked to be even through its canonical bits. The elements mapped
+/// to the exceptional points are not supported; they are found with
+/// negligible probability when the element is a hash.
+pub fn map_to_curve_gadget(
+    composer: &mut StandardComposer,
+    t: Variable,
+    t_value: BlsScalar,
+) -> Point {
+    let a = BlsScalar::from(MONTGOMERY_A);
+    let (x1_value, is_x1, _, v_value) = elligator(t_value);
+    let point = map_to_curve(t_value);
+
+    // x1·(1 + Z·t^2) = -A
+    let x1 = composer.add_input(x1_value);
+    let t_squared =
+        composer.mul(BlsScalar::one(), t, t, BlsScalar::zero(), None);
+    let x1_equation = composer.big_mul(
+        BlsScalar::from(ELLIGATOR_Z),
+        x1,
+        t_squared,
+        Some((BlsScalar::one(), x1)),
+        a,
+        None,
+    );
+    composer.constrain_to_constant(x1_equation, BlsScalar::zero(), None);
+
+    // u = x1 when the bit is set and u = x2 = -x1 - A otherwise
+    let bit = composer.add_input(BlsScalar::from(is_x1 as u64));
+    composer.boolean_gate(bit);
+    let selected =
+        composer.big_mul(BlsScalar::from(2), bit, x1, Some((a, bit)), -a, None);
+    let u = composer.big_add(
+        (BlsScalar::one(), selected),
+        (-BlsScalar::one(), x1),
+        None,
+        BlsScalar::zero(),
+        None,
+    );
+
+    // B·v^2 = u^3 + A·u^2 + u, with v even
+    let v = composer.add_input(v_value);
+    let u_squared =
+        composer.mul(BlsScalar::one(), u, u, BlsScalar::zero(), None);
+    let quadratic = composer.big_add(
+        (BlsScalar::one(), u_squared),
+        (a, u),
+        None,
+        BlsScalar::one(),
+        None,
+    );
+    let rhs =
+        composer.mul(BlsScalar::one(), u, quadratic, BlsScalar::zero(), None);
+    let lhs = composer.mul(
+        -BlsScalar::from(MONTGOMERY_MINUS_B),
+        v,
+        v,
+        BlsScalar::zero(),
+        None,
+    );
+    composer.assert_equal(lhs, rhs);
+    let v_bits = scalar_bits_gadget(composer, v, v_value, SCALAR_BITS);
+    composer.constrain_to_constant(v_bits[0], BlsScalar::zero(), None);
+
+    // x·v = u and y·(u + 1) = u - 1
+    let point = composer.add_affine(point);
+    let x_v =
+        composer.mul(BlsScalar::one(), *point.x(), v, BlsScalar::zero(), None);
+    composer.assert_equal(x_v, u);
+    let y_equation = composer.big_mul(
+        BlsScalar::one(),
+        *point.y(),
+        u,
+        Some((BlsScalar::one(), *point.y())),
+        BlsScalar::one(),
+        None,
+    );
+    composer.assert_equal(y_equation, u);
+
+    point
+}
+
+/// Hash a message given as byte variables to a point of the prime order
+/// subgroup of Jubjub in circuit, where the bytes of the message are also
+/// given as values. The tag and the length of the message are part of the
+/// circuit description.
+pub fn hash_to_curve_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    dst: &[u8],
+    msg: &[Variable],
+    msg_bytes: &[u8],
+) -> Point {
+    let t = hash_to_field_gadget(composer, constants, dst, msg, msg_bytes, 2);
+    let t_values = hash_to_field(dst, msg_bytes, 2);
+    let p0 = map_to_curve_gadget(composer, t[0], t_values[0]);
+    let p1 = map_to_curve_gadget(composer, t[1], t_values[1]);
+    let q = composer.point_addition_gate(p0, p1);
+
+    // Clear the cofactor of 8 by doubling three times
+    (0..3).fold(q, |point, _| composer.point_addition_gate(point, point))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+
+    #[test]
+    fn test_map_to_curve_vectors() {
+        // g(x1) is a square for 7 and not for 6
+        let point = map_to_curve(BlsScalar::from(7));
+        assert_eq!(
+            point.get_x(),
+            BlsScalar::from_raw([
+                0x063835af06a68fb9,
+                0x6b0083701a981447,
+                0x07c00d345a3bf879,
+                0x3aacf405eb697e5c,
+            ])
+        );
+        assert_eq!(
+            point.get_y(),
+            BlsScalar::from_raw([
+                0x50e1bea86770f2a7,
+                0x4fb4f11363496213,
+                0x68183383fb7d22d5,
+                0x575d633cdc836fc4,
+            ])
+        );
+        let point = map_to_curve(BlsScalar::from(6));
+        assert_eq!(
+            point.get_x(),
+            BlsScalar::from_raw([
+                0x57c5cd755ae94ea7,
+                0x17e297aba4f1b903,
+                0x22c501760f2d3af3,
+                0x6b16c9308ee366f8,
+            ])
+        );
+        assert_eq!(
+            point.get_y(),
+            BlsScalar::from_raw([
+                0x1e2c38aa3ac97bc9,
+                0x27f7711f79bfe8ba,
+                0xc1d31a711f65bc62,
+                0x47a98709e4586e5b,
+            ])
+        );
+
+        // Zero is sent to an exceptional point
+        assert_eq!(map_to_curve(BlsScalar::zero()), JubJubAffine::identity());
+    }
+
+    #[test]
+    fn test_hash_to_curve_vectors() {
+        let point = JubJubAffine::from(hash_to_curve(b"rc-test", b"abc"));
+        assert_eq!(
+            point.get_x(),
+            BlsScalar::from_raw([
+                0x236b19ee1f08166a,
+                0xcf9bdbd5c7a2c282,
+                0xb49a3dc39774538a,
+                0x6b60e3db3063a124,
+            ])
+        );
+        assert_eq!(
+            point.get_y(),
+            BlsScalar::from_raw([
+                0xdaa090f6c2bf52a4,
+                0x305759ade0925c5f,
+                0x5bf8ecbd58109f73,
+                0x40d15e9918cccf05,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_map_to_curve_is_on_curve() {
+        (0..16u64).for_each(|t| {
+            let point = map_to_curve(BlsScalar::from(t) - BlsScalar::from(8));
+            assert!(bool::from(point.is_on_curve_vartime()));
+        });
+    }
+
+    #[test]
+    fn test_hash_to_curve_prime_order() {
+        let point = hash_to_curve(b"rc-test", b"abc");
+        assert!(bool::from(point.is_prime_order()));
+        assert_eq!(point, hash_to_curve(b"rc-test", b"abc"));
+        assert_ne!(point, hash_to_curve(b"rc-test", b"abd"));
+        assert_ne!(point, hash_to_curve(b"rc-tesu", b"abc"));
+    }
+
+    #[test]
+    fn test_hash_to_curve_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let msg_bytes = b"abc";
+                let msg: Vec<Variable> = msg_bytes
+                    .iter()
+                    .map(|byte| {
+                        composer.add_input(BlsScalar::from(*byte as u64))
+                    })
+                    .collect();
+                let point = hash_to_curve_gadget(
+                    composer, &constants, b"rc-test", &msg, msg_bytes,
+                );
+                composer.assert_equal_public_point(
+                    point,
+                    JubJubAffine::from(hash_to_curve(b"rc-test", msg_bytes)),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            9000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_map_to_curve_gadget_wrong_point_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                // The point for 6 takes the second candidate
+                let t = composer.add_input(BlsScalar::from(6));
+                let point =
+                    map_to_curve_gadget(composer, t, BlsScalar::from(6));
+                composer.assert_equal_public_point(
+                    point,
+                    map_to_curve(BlsScalar::from(7)),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            5000,
+        );
+        assert!(res.is_err());
+    }
+}
@@ -4,7 +4,13 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+pub mod cost;
 pub mod divide_w_recip;
 pub mod gadgets;
+#[cfg(feature = "halo2")]
+pub mod halo2;
+pub mod hash_to_field;
+#[cfg(feature = "arkworks")]
+pub mod r1cs;
 pub mod sponge;
 pub mod zelbet;
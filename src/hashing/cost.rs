@@ -0,0 +1,580 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Breakdown of the cost of the Zelbet circuits per component, to size the
+//! circuits that use them.
+//!
+//! The cost of each component is measured on a composer, as the gates that
+//! its gadget adds to it, and scaled by the number of times the component
+//! appears in the circuit. The tests check the totals against the gates of the
+//! whole gadgets. Witnesses are not reported, as the composer does not expose
+//! its number of variables, and its gates allocate their outputs internally.
+
+use super::gadgets::{
+    accumulator_gadget, bar_lookup_table, brick_gadget,
+    comparison_flags_gadget, comparison_start_gadget,
+    comparison_transition_gadget, concrete_gadget, digits_gadget,
+    s_box_lookup_gadget, BarWitness,
+};
+use super::sponge::absorb_gadget;
+use super::zelbet::ZelbetConstants;
+use crate::constants::{
+    DECOMPOSITION_S_I, MONTGOMERY_FOUR, MONTGOMERY_THREE, VU_256,
+};
+use crate::merkle::merkle_domain_gadget;
+use dusk_plonk::constraint_system::StandardComposer;
+use dusk_plonk::prelude::BlsScalar;
+use std::fmt;
+
+/// Number of concrete layers in a permutation
+const CONCRETE_LAYERS: usize = 8;
+/// Number of brick layers in a permutation
+const BRICK_LAYERS: usize = 7;
+/// Number of digits of the decomposition of the bar function
+const DIGITS: usize = 27;
+
+/// Part of a circuit whose cost is reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Component {
+    /// Constants of [`ZelbetConstants`], added once per circuit
+    Constants,
+    /// Concrete layers
+    Concrete,
+    /// Brick layers
+    Brick,
+    /// Decomposition of the inputs of bar, and its recomposition check
+    BarDecomposition,
+    /// Lookups of the S-box, and their outputs
+    SBox,
+    /// Comparison flags `c_i`, identity flags `z_i` and the transitions of
+    /// the comparison state
+    Comparison,
+    /// Recomposition of the outputs of the S-box
+    Accumulator,
+    /// Addition of the input blocks to the sponge state
+    SpongeAbsorption,
+}
+
+impl Component {
+    /// Name of the component in the reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            Component::Constants => "constants",
+            Component::Concrete => "concrete",
+            Component::Brick => "brick",
+            Component::BarDecomposition => "bar decomposition",
+            Component::SBox => "s-box",
+            Component::Comparison => "c_i/z_i checks",
+            Component::Accumulator => "accumulator",
+            Component::SpongeAbsorption => "sponge absorption",
+        }
+    }
+}
+
+/// Cost of a component
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cost {
+    pub arithmetic_gates: usize,
+    pub plookup_gates: usize,
+    /// Rows of [`bar_lookup_table`] queried by the component, counted once
+    /// per circuit
+    pub lookup_table_rows: usize,
+}
+
+impl Cost {
+    /// Cost of arithmetic gates, as measured by [`measure`]
+    fn arithmetic(gates: usize) -> Cost {
+        Cost {
+            arithmetic_gates: gates,
+            ..Cost::default()
+        }
+    }
+
+    /// Cost of plookup gates, as measured by [`measure`]
+    fn plookup(gates: usize) -> Cost {
+        Cost {
+            plookup_gates: gates,
+            ..Cost::default()
+        }
+    }
+
+    /// Gates of both kinds, which add up to the circuit size
+    pub fn gates(&self) -> usize {
+        self.arithmetic_gates + self.plookup_gates
+    }
+
+    /// Cost of the gates repeated `n` times, with the same
+    /// lookup table rows
+    fn times(self, n: usize) -> Cost {
+        Cost {
+            arithmetic_gates: n * self.arithmetic_gates,
+            plookup_gates: n * self.plookup_gates,
+            lookup_table_rows: self.lookup_table_rows,
+        }
+    }
+
+    fn add(self, other: Cost) -> Cost {
+        Cost {
+            arithmetic_gates: self.arithmetic_gates + other.arithmetic_gates,
+            plookup_gates: self.plookup_gates + other.plookup_gates,
+            lookup_table_rows: self.lookup_table_rows + other.lookup_table_rows,
+        }
+    }
+}
+
+/// Run the gadget on the composer, and return its output along with the
+/// number of gates that it added
+fn measure<T>(
+    composer: &mut StandardComposer,
+    gadget: impl FnOnce(&mut StandardComposer) -> T,
+) -> (T, usize) {
+    let gates = composer.circuit_size();
+    let output = gadget(composer);
+
+    (output, composer.circuit_size() - gates)
+}
+
+/// Cost of a single instance of each component, measured on a composer
+struct UnitCosts {
+    constants: Cost,
+    merkle_domain: Cost,
+    concrete_layer: Cost,
+    brick_layer: Cost,
+    /// Components of a single bar, without the lookup table
+    bar: [(Component, Cost); 4],
+    /// Absorption of a block of two inputs
+    absorption: Cost,
+}
+
+impl UnitCosts {
+    fn measure() -> Self {
+        let mut composer = StandardComposer::new();
+        let (constants, constants_cost) =
+            measure(&mut composer, ZelbetConstants::new);
+        let (_, merkle_domain) = measure(&mut composer, merkle_domain_gadget);
+        let one = BlsScalar::one();
+        let state = [composer.add_input(one); 3];
+
+        let (_, concrete_layer) = measure(&mut composer, |composer| {
+            concrete_gadget(composer, &state, &[one; 3], &[constants.one; 3], 0)
+        });
+        let (_, brick_layer) = measure(&mut composer, |composer| {
+            brick_gadget(
+                composer,
+                &state,
+                &[one; 3],
+                constants.two,
+                MONTGOMERY_THREE,
+                MONTGOMERY_FOUR,
+            )
+        });
+
+        // The pieces of bar_gadget, on its most significant digit
+        let witness = BarWitness::new(one);
+        let (digits, decomposition) = measure(&mut composer, |composer| {
+            digits_gadget(composer, state[0], &witness.digits)
+        });
+        let ((tag, comparison_state), start) =
+            measure(&mut composer, comparison_start_gadget);
+        let ((c_i, _, scaled_z_i), flags) =
+            measure(&mut composer, |composer| {
+                comparison_flags_gadget(
+                    composer,
+                    [witness.flags[26], witness.identity_flags[26]],
+                    1,
+                )
+            });
+        let (y_i, s_box) = measure(&mut composer, |composer| {
+            s_box_lookup_gadget(
+                composer,
+                digits[26],
+                scaled_z_i,
+                c_i,
+                witness.s_box[26],
+            )
+        });
+        let (_, transition) = measure(&mut composer, |composer| {
+            comparison_transition_gadget(
+                composer,
+                tag,
+                comparison_state,
+                c_i,
+                witness.states[26],
+            )
+        });
+        let (_, accumulator) = measure(&mut composer, |composer| {
+            accumulator_gadget(composer, y_i, y_i, 25, witness.accumulators[25])
+        });
+
+        let (_, absorption) = measure(&mut composer, |composer| {
+            let mut sponge_state = state;
+            absorb_gadget(
+                composer,
+                &mut sponge_state,
+                &mut [one; 3],
+                [state[0], state[1]],
+                [one; 2],
+            )
+        });
+
+        UnitCosts {
+            constants: Cost::arithmetic(constants_cost),
+            merkle_domain: Cost::arithmetic(merkle_domain),
+            concrete_layer: Cost::arithmetic(concrete_layer),
+            brick_layer: Cost::arithmetic(brick_layer),
+            bar: [
+                (Component::BarDecomposition, Cost::arithmetic(decomposition)),
+                (Component::SBox, Cost::plookup(s_box).times(DIGITS)),
+                // The tag and the initial state, then for each digit its
+                // flags and the transition to the next state
+                (
+                    Component::Comparison,
+                    Cost::arithmetic(start)
+                        .add(Cost::arithmetic(flags).times(DIGITS))
+                        .add(Cost::plookup(transition).times(DIGITS)),
+                ),
+                // One step per digit below the most significant one
+                (
+                    Component::Accumulator,
+                    Cost::arithmetic(accumulator).times(DIGITS - 1),
+                ),
+            ],
+            absorption: Cost::arithmetic(absorption),
+        }
+    }
+}
+
+/// Breakdown of the cost of a circuit per component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CostReport {
+    /// Circuit whose cost is reported
+    pub configuration: String,
+    pub components: Vec<(Component, Cost)>,
+}
+
+impl CostReport {
+    /// Cost of `permutations` Zelbet permutations sharing their constants
+    fn permutations(
+        configuration: String,
+        units: &UnitCosts,
+        permutations: usize,
+    ) -> Self {
+        // Rows of the digits at every position, and those of the transitions
+        let v = VU_256.0[0];
+        let digit_rows =
+            (v + DECOMPOSITION_S_I.iter().map(|s| s.0[0] - v).sum::<u64>())
+                as usize;
+        let transition_rows = bar_lookup_table().0.len() - digit_rows;
+
+        let mut components = vec![
+            (Component::Constants, units.constants),
+            (
+                Component::Concrete,
+                units.concrete_layer.times(CONCRETE_LAYERS * permutations),
+            ),
+            (
+                Component::Brick,
+                units.brick_layer.times(BRICK_LAYERS * permutations),
+            ),
+        ];
+        units.bar.iter().for_each(|(component, cost)| {
+            let mut cost = cost.times(3 * permutations);
+            cost.lookup_table_rows = match component {
+                Component::SBox => digit_rows,
+                Component::Comparison => transition_rows,
+                _ => 0,
+            };
+            components.push((*component, cost));
+        });
+
+        CostReport {
+            configuration,
+            components,
+        }
+    }
+
+    /// Cost of a single Zelbet permutation, with its constants
+    pub fn permutation() -> Self {
+        let units = UnitCosts::measure();
+        Self::permutations(String::from("permutation"), &units, 1)
+    }
+
+    /// Cost of the compression of two nodes of a Merkle tree, which is a
+    /// single permutation with the Merkle domain added to the constants
+    pub fn compression() -> Self {
+        let units = UnitCosts::measure();
+        let mut report =
+            Self::permutations(String::from("compression"), &units, 1);
+        report.components[0].1 = units.constants.add(units.merkle_domain);
+        report
+    }
+
+    /// Cost of the sponge with `length_in` inputs and `length_out` outputs
+    pub fn sponge(length_in: usize, length_out: usize) -> Self {
+        assert!(length_in > 0, "the sponge takes at least one input");
+        let blocks = (length_in + 1) / 2;
+        let squeezes = match length_out > 1 {
+            true => (length_out + 1) / 2 - 1,
+            false => 0,
+        };
+
+        let units = UnitCosts::measure();
+        let mut report = Self::permutations(
+            format!("sponge({}, {})", length_in, length_out),
+            &units,
+            blocks + squeezes,
+        );
+        report.components.push((
+            Component::SpongeAbsorption,
+            units.absorption.times(blocks - 1),
+        ));
+
+        report
+    }
+
+    /// Sum of the costs of the components
+    pub fn total(&self) -> Cost {
+        self.components
+            .iter()
+            .fold(Cost::default(), |acc, (_, cost)| acc.add(*cost))
+    }
+
+    /// Report as a JSON object
+    pub fn to_json(&self) -> String {
+        let cost_json = |cost: &Cost| {
+            format!(
+                "\"arithmetic_gates\": {}, \"plookup_gates\": {}, \
+                 \"lookup_table_rows\": {}",
+                cost.arithmetic_gates,
+                cost.plookup_gates,
+                cost.lookup_table_rows
+            )
+        };
+        let components: Vec<String> = self
+            .components
+            .iter()
+            .map(|(component, cost)| {
+                format!(
+                    "{{\"component\": \"{}\", {}}}",
+                    component.name(),
+                    cost_json(cost)
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"configuration\": \"{}\", \"components\": [{}], \"total\": {{{}}}}}",
+            self.configuration,
+            components.join(", "),
+            cost_json(&self.total())
+        )
+    }
+}
+
+impl fmt::Display for CostReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.configuration)?;
+        writeln!(
+            f,
+            "{:<20}{:>12}{:>12}{:>12}",
+            "component", "arithmetic", "plookup", "table rows"
+        )?;
+        let total = self.total();
+        self.components
+            .iter()
+            .map(|(component, cost)| (component.name(), cost))
+            .chain(core::iter::once(("total", &total)))
+            .try_for_each(|(name, cost)| {
+                writeln!(
+                    f,
+                    "{:<20}{:>12}{:>12}{:>12}",
+                    name,
+                    cost.arithmetic_gates,
+                    cost.plookup_gates,
+                    cost.lookup_table_rows
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashing::gadgets::{
+        bar_gadget, decomposition_gadget, s_box_and_constraints, COMPARISON_TAG,
+    };
+    use crate::hashing::sponge::sponge_zelbet_gadget;
+    use crate::merkle::merkle_compress_gadget;
+    use dusk_plonk::constraint_system::Variable;
+
+    fn cost(report: &CostReport, component: Component) -> Cost {
+        report
+            .components
+            .iter()
+            .find(|(c, _)| *c == component)
+            .map(|(_, cost)| *cost)
+            .unwrap_or_default()
+    }
+
+    /// Check every field of the total cost against the gates measured on a
+    /// whole gadget, of which there are two lookups for each digit of the bars
+    fn assert_total(report: &CostReport, measured: usize, bars: usize) {
+        let total = report.total();
+        assert_eq!(total.gates(), measured);
+        assert_eq!(total.plookup_gates, 2 * DIGITS * bars);
+        assert_eq!(total.arithmetic_gates, measured - 2 * DIGITS * bars);
+        assert_eq!(total.lookup_table_rows, bar_lookup_table().0.len());
+    }
+
+    #[test]
+    fn test_component_costs() {
+        let report = CostReport::permutation();
+        let mut composer = StandardComposer::new();
+        let (constants, measured) =
+            measure(&mut composer, ZelbetConstants::new);
+        assert_eq!(
+            cost(&report, Component::Constants),
+            Cost::arithmetic(measured)
+        );
+        let one = BlsScalar::one();
+        let state = [composer.add_input(one); 3];
+
+        let (_, measured) = measure(&mut composer, |composer| {
+            concrete_gadget(composer, &state, &[one; 3], &[constants.one; 3], 0)
+        });
+        assert_eq!(
+            cost(&report, Component::Concrete),
+            Cost::arithmetic(measured).times(CONCRETE_LAYERS)
+        );
+
+        let (_, measured) = measure(&mut composer, |composer| {
+            brick_gadget(
+                composer,
+                &state,
+                &[one; 3],
+                constants.two,
+                MONTGOMERY_THREE,
+                MONTGOMERY_FOUR,
+            )
+        });
+        assert_eq!(
+            cost(&report, Component::Brick),
+            Cost::arithmetic(measured).times(BRICK_LAYERS)
+        );
+
+        let (_, measured) = measure(&mut composer, |composer| {
+            decomposition_gadget(composer, state[0], one)
+        });
+        assert_eq!(
+            cost(&report, Component::BarDecomposition),
+            Cost::arithmetic(measured).times(3)
+        );
+
+        // A lookup per digit
+        let s_box = cost(&report, Component::SBox);
+        assert_eq!(s_box.arithmetic_gates, 0);
+        assert_eq!(s_box.plookup_gates, 3 * DIGITS);
+
+        // The S-box of a digit with its flags, then the lookup of the
+        // transition to the next state
+        let (_, measured) = measure(&mut composer, |composer| {
+            s_box_and_constraints(composer, state[0], 1, 1)
+        });
+        let (_, start) = measure(&mut composer, comparison_start_gadget);
+        let comparison = cost(&report, Component::Comparison);
+        assert_eq!(comparison.plookup_gates, 3 * DIGITS);
+        assert_eq!(
+            s_box.gates() + comparison.gates(),
+            3 * (start + DIGITS * (measured + 1))
+        );
+
+        // The components of bar add up to the whole gadget
+        let (_, measured) = measure(&mut composer, |composer| {
+            bar_gadget(composer, state[0], one)
+        });
+        let bar = [
+            Component::BarDecomposition,
+            Component::SBox,
+            Component::Comparison,
+            Component::Accumulator,
+        ]
+        .iter()
+        .fold(Cost::default(), |acc, c| acc.add(cost(&report, *c)));
+        assert_eq!(bar.gates(), 3 * measured);
+        assert_eq!(bar.plookup_gates, 3 * 2 * DIGITS);
+    }
+
+    #[test]
+    fn test_permutation_and_compression_costs() {
+        let mut composer = StandardComposer::new();
+        let state: [Variable; 3] = [composer.add_input(BlsScalar::one()); 3];
+        let (_, measured) = measure(&mut composer, |composer| {
+            let constants = ZelbetConstants::new(composer);
+            constants.permute(composer, &state, &[BlsScalar::one(); 3])
+        });
+        assert_total(&CostReport::permutation(), measured, 3);
+
+        let mut composer = StandardComposer::new();
+        let node = composer.add_input(BlsScalar::one());
+        let (_, measured) = measure(&mut composer, |composer| {
+            let constants = ZelbetConstants::new(composer);
+            merkle_compress_gadget(
+                composer,
+                &constants,
+                node,
+                BlsScalar::one(),
+                node,
+                BlsScalar::one(),
+            )
+        });
+        assert_total(&CostReport::compression(), measured, 3);
+    }
+
+    #[test]
+    fn test_sponge_costs() {
+        [(1, 1), (2, 1), (5, 2), (4, 3), (6, 6)].iter().for_each(
+            |&(length_in, length_out)| {
+                let mut composer = StandardComposer::new();
+                let input =
+                    vec![composer.add_input(BlsScalar::one()); length_in];
+                let (_, measured) = measure(&mut composer, |composer| {
+                    sponge_zelbet_gadget(
+                        composer,
+                        input,
+                        &vec![BlsScalar::one(); length_in],
+                        length_out,
+                    )
+                });
+                // One permutation per input block, and one per extra output
+                // block
+                let permutations = (length_in + 1) / 2 + (length_out - 1) / 2;
+                let report = CostReport::sponge(length_in, length_out);
+                assert_total(&report, measured, 3 * permutations);
+            },
+        );
+    }
+
+    #[test]
+    fn test_lookup_table_rows() {
+        let report = CostReport::sponge(3, 2);
+        let table = bar_lookup_table();
+        let transition_rows = table
+            .0
+            .iter()
+            .filter(|row| row[1] == BlsScalar::from(COMPARISON_TAG))
+            .count();
+        assert_eq!(
+            cost(&report, Component::SBox).lookup_table_rows,
+            table.0.len() - transition_rows
+        );
+        assert_eq!(
+            cost(&report, Component::Comparison).lookup_table_rows,
+            transition_rows
+        );
+        assert!(report.to_json().contains("\"component\": \"s-box\""));
+        assert!(format!("{}", report).starts_with("sponge(3, 2)"));
+    }
+}
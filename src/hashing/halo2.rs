@@ -0,0 +1,787 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! halo2 chip for the Zelbet permutation, enabled by the `halo2` feature.
+//!
+//! The chip is generic over the field and the [`Parameters`] of the hash, with
+//! an instance for the base field of Pallas in [`pasta`]. Concrete and brick
+//! are custom gates from one row of the state to the next. Bar decomposes each
+//! element over one row per digit, with halo2 lookups into a table of the
+//! S-box and into a range table, and the comparison with the digits of p - 1
+//! of [`bar_arithmetic_gadget`].
+//!
+//! [`bar_arithmetic_gadget`]: crate::hashing::gadgets::bar_arithmetic_gadget
+
+pub mod pasta;
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{AssignedCell, Chip, Layouter, Region, SimpleFloorPlanner},
+    plonk::{
+        Advice, Circuit, Column, ConstraintSystem, Error, Expression, Fixed,
+        Instance, Selector, TableColumn,
+    },
+    poly::Rotation,
+};
+
+/// Number of bits of the range table, enough for every radix `s_k`
+pub const DIGIT_BITS: usize = 10;
+
+/// Parameters of Reinforced Concrete over a field
+pub trait Parameters<F: FieldExt>: Debug + Clone {
+    /// Prime below every radix and every digit of p - 1, the S-box being the
+    /// identity from it on
+    const V: u64;
+
+    /// Radices `s_k` of the decomposition, from the least significant digit,
+    /// each at most `2^DIGIT_BITS`
+    fn radices() -> &'static [u64];
+
+    /// Decomposition of p - 1 in the radices `s_k`
+    fn modulus_digits() -> &'static [u64];
+
+    /// S-box, a permutation of the integers below `V`
+    fn s_box() -> &'static [u64];
+
+    /// MDS matrix of the concrete layer
+    fn matrix() -> [[F; 3]; 3];
+
+    /// Round constants of the concrete layers
+    fn round_constants() -> [[F; 3]; 6];
+
+    /// Coefficients `[alpha, beta]` of the brick layer, for the second and
+    /// the third element
+    fn brick_coefficients() -> [[F; 2]; 2];
+}
+
+/// S-box of the bar function on a digit
+pub fn s_box<F: FieldExt, P: Parameters<F>>(x: u64) -> u64 {
+    match x < P::V {
+        true => P::s_box()[x as usize],
+        false => x,
+    }
+}
+
+/// Decomposition of a field element in the radices `s_k`, from the least
+/// significant digit
+pub fn decomposition<F: FieldExt, P: Parameters<F>>(x: &F) -> Vec<u64> {
+    let mut limbs = [0u64; 4];
+    x.to_repr()
+        .as_ref()
+        .chunks(8)
+        .zip(limbs.iter_mut())
+        .for_each(|(bytes, limb)| {
+            let mut buffer = [0u8; 8];
+            buffer.copy_from_slice(bytes);
+            *limb = u64::from_le_bytes(buffer);
+        });
+
+    P::radices()
+        .iter()
+        .map(|&s| {
+            let mut remainder = 0u128;
+            limbs.iter_mut().rev().for_each(|limb| {
+                let current = (remainder << 64) | *limb as u128;
+                *limb = (current / s as u128) as u64;
+                remainder = current % s as u128;
+            });
+            remainder as u64
+        })
+        .collect()
+}
+
+/// Concrete layer (out of circuit)
+pub fn concrete<F: FieldExt, P: Parameters<F>>(
+    state: [F; 3],
+    round: usize,
+) -> [F; 3] {
+    let matrix = P::matrix();
+    let mut new_state = P::round_constants()[round];
+    (0..3).for_each(|i| {
+        (0..3).for_each(|j| new_state[i] += matrix[i][j] * state[j]);
+    });
+
+    new_state
+}
+
+/// Brick layer (out of circuit)
+pub fn brick<F: FieldExt, P: Parameters<F>>(state: [F; 3]) -> [F; 3] {
+    let [[alpha_1, beta_1], [alpha_2, beta_2]] = P::brick_coefficients();
+    let x_squared = state[0].square();
+
+    [
+        x_squared.square() * state[0],
+        state[1] * (x_squared + alpha_1 * state[0] + beta_1),
+        state[2] * (state[1].square() + alpha_2 * state[1] + beta_2),
+    ]
+}
+
+/// Bar function on a single element (out of circuit)
+pub fn bar<F: FieldExt, P: Parameters<F>>(x: &F) -> F {
+    P::radices()
+        .iter()
+        .zip(decomposition::<F, P>(x).iter())
+        .rev()
+        .fold(F::zero(), |acc, (&s, &x)| {
+            acc * F::from(s) + F::from(s_box::<F, P>(x))
+        })
+}
+
+/// Zelbet permutation, with the same layers as `zelbet_out_of_circuit` (out
+/// of circuit)
+pub fn permutation<F: FieldExt, P: Parameters<F>>(state: [F; 3]) -> [F; 3] {
+    let mut state = concrete::<F, P>(state, 0);
+    state = brick::<F, P>(state);
+    state = concrete::<F, P>(state, 1);
+    state = brick::<F, P>(state);
+    state = concrete::<F, P>(state, 2);
+    state = brick::<F, P>(state);
+    state = concrete::<F, P>(state, 3);
+    state = [
+        bar::<F, P>(&state[0]),
+        bar::<F, P>(&state[1]),
+        bar::<F, P>(&state[2]),
+    ];
+    state = concrete::<F, P>(state, 4);
+    state = brick::<F, P>(state);
+    state = concrete::<F, P>(state, 5);
+    state = brick::<F, P>(state);
+    state = concrete::<F, P>(state, 4);
+    state = brick::<F, P>(state);
+    concrete::<F, P>(state, 3)
+}
+
+/// Columns, selectors and tables of the chip
+#[derive(Debug, Clone)]
+pub struct ReinforcedConcreteConfig<F: FieldExt> {
+    state: [Column<Advice>; 3],
+    round_constants: [Column<Fixed>; 3],
+    q_concrete: Selector,
+    q_brick: Selector,
+
+    digit: Column<Advice>,
+    image: Column<Advice>,
+    composition: Column<Advice>,
+    output: Column<Advice>,
+    lt: Column<Advice>,
+    gt: Column<Advice>,
+    smaller: Column<Advice>,
+    radix: Column<Fixed>,
+    modulus_digit: Column<Fixed>,
+    q_bar_init: Selector,
+    q_bar: Selector,
+
+    table_tag: TableColumn,
+    table_digit: TableColumn,
+    table_image: TableColumn,
+    table_range: TableColumn,
+    _marker: PhantomData<F>,
+}
+
+/// Chip of the Zelbet permutation
+#[derive(Debug, Clone)]
+pub struct ReinforcedConcreteChip<F: FieldExt, P: Parameters<F>> {
+    config: ReinforcedConcreteConfig<F>,
+    _marker: PhantomData<P>,
+}
+
+impl<F: FieldExt, P: Parameters<F>> Chip<F> for ReinforcedConcreteChip<F, P> {
+    type Config = ReinforcedConcreteConfig<F>;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: FieldExt, P: Parameters<F>> ReinforcedConcreteChip<F, P> {
+    pub fn construct(config: ReinforcedConcreteConfig<F>) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+    ) -> ReinforcedConcreteConfig<F> {
+        let state = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+        let round_constants = [
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+        ];
+        state
+            .iter()
+            .for_each(|column| meta.enable_equality(*column));
+
+        let config = ReinforcedConcreteConfig {
+            state,
+            round_constants,
+            q_concrete: meta.selector(),
+            q_brick: meta.selector(),
+            digit: meta.advice_column(),
+            image: meta.advice_column(),
+            composition: meta.advice_column(),
+            output: meta.advice_column(),
+            lt: meta.advice_column(),
+            gt: meta.advice_column(),
+            smaller: meta.advice_column(),
+            radix: meta.fixed_column(),
+            modulus_digit: meta.fixed_column(),
+            q_bar_init: meta.selector(),
+            q_bar: meta.complex_selector(),
+            table_tag: meta.lookup_table_column(),
+            table_digit: meta.lookup_table_column(),
+            table_image: meta.lookup_table_column(),
+            table_range: meta.lookup_table_column(),
+            _marker: PhantomData,
+        };
+        meta.enable_equality(config.composition);
+        meta.enable_equality(config.output);
+
+        // The next row of the state is the product of the current one by the
+        // MDS matrix, plus the round constants
+        meta.create_gate("concrete", |meta| {
+            let q = meta.query_selector(config.q_concrete);
+            let matrix = P::matrix();
+            (0..3)
+                .map(|i| {
+                    let sum = (0..3).fold(
+                        meta.query_fixed(
+                            config.round_constants[i],
+                            Rotation::cur(),
+                        ),
+                        |acc, j| {
+                            acc + meta
+                                .query_advice(config.state[j], Rotation::cur())
+                                * matrix[i][j]
+                        },
+                    );
+                    q.clone()
+                        * (meta.query_advice(config.state[i], Rotation::next())
+                            - sum)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        // The next row of the state is the brick of the current one
+        meta.create_gate("brick", |meta| {
+            let q = meta.query_selector(config.q_brick);
+            let [[alpha_1, beta_1], [alpha_2, beta_2]] =
+                P::brick_coefficients();
+            let x: Vec<Expression<F>> = config
+                .state
+                .iter()
+                .map(|column| meta.query_advice(*column, Rotation::cur()))
+                .collect();
+            let y: Vec<Expression<F>> = config
+                .state
+                .iter()
+                .map(|column| meta.query_advice(*column, Rotation::next()))
+                .collect();
+            let x_squared = x[0].clone() * x[0].clone();
+
+            vec![
+                q.clone()
+                    * (y[0].clone()
+                        - x_squared.clone() * x_squared.clone() * x[0].clone()),
+                q.clone()
+                    * (y[1].clone()
+                        - x[1].clone()
+                            * (x_squared
+                                + x[0].clone() * alpha_1
+                                + Expression::Constant(beta_1))),
+                q * (y[2].clone()
+                    - x[2].clone()
+                        * (x[1].clone() * x[1].clone()
+                            + x[1].clone() * alpha_2
+                            + Expression::Constant(beta_2))),
+            ]
+        });
+
+        // The recompositions and the comparison state start from zero
+        meta.create_gate("bar init", |meta| {
+            let q = meta.query_selector(config.q_bar_init);
+            vec![
+                q.clone()
+                    * meta.query_advice(config.composition, Rotation::cur()),
+                q.clone() * meta.query_advice(config.output, Rotation::cur()),
+                q * meta.query_advice(config.smaller, Rotation::cur()),
+            ]
+        });
+
+        // For the digit `x_k` of the row, from the most significant one:
+        // - The digits and the outputs of the S-box are recomposed.
+        // - The booleans `lt` and `gt` compare `x_k` with `v_k`, at most one of
+        //   them being set, and `x_k = v_k` when neither is.
+        // - `gt` is only allowed once a digit was smaller, so the decomposition
+        //   is at most that of p - 1.
+        meta.create_gate("bar", |meta| {
+            let q = meta.query_selector(config.q_bar);
+            let one = Expression::Constant(F::one());
+            let x = meta.query_advice(config.digit, Rotation::cur());
+            let y = meta.query_advice(config.image, Rotation::cur());
+            let lt = meta.query_advice(config.lt, Rotation::cur());
+            let gt = meta.query_advice(config.gt, Rotation::cur());
+            let radix = meta.query_fixed(config.radix, Rotation::cur());
+            let v = meta.query_fixed(config.modulus_digit, Rotation::cur());
+            let smaller = meta.query_advice(config.smaller, Rotation::cur());
+            let smaller_prev =
+                meta.query_advice(config.smaller, Rotation::prev());
+            let composition =
+                meta.query_advice(config.composition, Rotation::cur());
+            let composition_prev =
+                meta.query_advice(config.composition, Rotation::prev());
+            let output = meta.query_advice(config.output, Rotation::cur());
+            let output_prev =
+                meta.query_advice(config.output, Rotation::prev());
+
+            vec![
+                composition - (composition_prev * radix.clone() + x.clone()),
+                output - (output_prev * radix + y),
+                lt.clone() * (one.clone() - lt.clone()),
+                gt.clone() * (one.clone() - gt.clone()),
+                lt.clone() * gt.clone(),
+                (one.clone() - lt.clone() - gt.clone()) * (x - v),
+                gt * (one - smaller_prev.clone()),
+                smaller
+                    - (smaller_prev.clone() + lt.clone() - smaller_prev * lt),
+            ]
+            .into_iter()
+            .map(|constraint| q.clone() * constraint)
+            .collect::<Vec<_>>()
+        });
+
+        // The digit and its image are a row of the S-box table, whose tag
+        // is zero only on the row matched by the disabled rows
+        meta.lookup(|meta| {
+            let q = meta.query_selector(config.q_bar);
+            let x = meta.query_advice(config.digit, Rotation::cur());
+            let y = meta.query_advice(config.image, Rotation::cur());
+            vec![
+                (q.clone(), config.table_tag),
+                (q.clone() * x, config.table_digit),
+                (q * y, config.table_image),
+            ]
+        });
+
+        // The digit is smaller than its radix
+        meta.lookup(|meta| {
+            let q = meta.query_selector(config.q_bar);
+            let x = meta.query_advice(config.digit, Rotation::cur());
+            let radix = meta.query_fixed(config.radix, Rotation::cur());
+            vec![(
+                q * (radix - Expression::Constant(F::one()) - x),
+                config.table_range,
+            )]
+        });
+
+        // The inequality claimed by `lt` or `gt` holds
+        meta.lookup(|meta| {
+            let q = meta.query_selector(config.q_bar);
+            let one = Expression::Constant(F::one());
+            let x = meta.query_advice(config.digit, Rotation::cur());
+            let lt = meta.query_advice(config.lt, Rotation::cur());
+            let gt = meta.query_advice(config.gt, Rotation::cur());
+            let v = meta.query_fixed(config.modulus_digit, Rotation::cur());
+            let distance =
+                lt * (v.clone() - one.clone() - x.clone()) + gt * (x - v - one);
+            vec![(q * distance, config.table_range)]
+        });
+
+        config
+    }
+
+    /// Load the range table and the table of the S-box
+    pub fn load_tables(
+        &self,
+        layouter: &mut impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let config = &self.config;
+
+        layouter.assign_table(
+            || "range",
+            |mut table| {
+                (0..1 << DIGIT_BITS).try_for_each(|x| {
+                    table.assign_cell(
+                        || "range",
+                        config.table_range,
+                        x,
+                        || Ok(F::from(x as u64)),
+                    )
+                })
+            },
+        )?;
+
+        layouter.assign_table(
+            || "s-box",
+            |mut table| {
+                let rows = core::iter::once((F::zero(), F::zero(), F::zero()))
+                    .chain((0..1 << DIGIT_BITS).map(|x: u64| {
+                        (F::one(), F::from(x), F::from(s_box::<F, P>(x)))
+                    }));
+                rows.enumerate().try_for_each(|(offset, (tag, x, y))| {
+                    table.assign_cell(
+                        || "tag",
+                        config.table_tag,
+                        offset,
+                        || Ok(tag),
+                    )?;
+                    table.assign_cell(
+                        || "digit",
+                        config.table_digit,
+                        offset,
+                        || Ok(x),
+                    )?;
+                    table.assign_cell(
+                        || "image",
+                        config.table_image,
+                        offset,
+                        || Ok(y),
+                    )
+                })
+            },
+        )
+    }
+
+    /// Assign a state to a row of the state columns
+    pub fn assign_state(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        state: Option<[F; 3]>,
+    ) -> Result<[AssignedCell<F, F>; 3], Error> {
+        let mut cell = |i: usize| {
+            region.assign_advice(
+                || "state",
+                self.config.state[i],
+                offset,
+                || state.map(|state| state[i]).ok_or(Error::Synthesis),
+            )
+        };
+
+        Ok([cell(0)?, cell(1)?, cell(2)?])
+    }
+
+    /// Zelbet permutation of the state
+    pub fn permutation(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: [AssignedCell<F, F>; 3],
+    ) -> Result<[AssignedCell<F, F>; 3], Error> {
+        let mut state = self.concrete(layouter, &state, 0)?;
+        state = self.brick(layouter, &state)?;
+        state = self.concrete(layouter, &state, 1)?;
+        state = self.brick(layouter, &state)?;
+        state = self.concrete(layouter, &state, 2)?;
+        state = self.brick(layouter, &state)?;
+        state = self.concrete(layouter, &state, 3)?;
+        state = [
+            self.bar(layouter, &state[0])?,
+            self.bar(layouter, &state[1])?,
+            self.bar(layouter, &state[2])?,
+        ];
+        state = self.concrete(layouter, &state, 4)?;
+        state = self.brick(layouter, &state)?;
+        state = self.concrete(layouter, &state, 5)?;
+        state = self.brick(layouter, &state)?;
+        state = self.concrete(layouter, &state, 4)?;
+        state = self.brick(layouter, &state)?;
+        self.concrete(layouter, &state, 3)
+    }
+
+    /// Concrete layer
+    fn concrete(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedCell<F, F>; 3],
+        round: usize,
+    ) -> Result<[AssignedCell<F, F>; 3], Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "concrete",
+            |mut region| {
+                config.q_concrete.enable(&mut region, 0)?;
+                let round_constants = P::round_constants()[round];
+                (0..3).try_for_each(|i| {
+                    state[i].copy_advice(
+                        || "state",
+                        &mut region,
+                        config.state[i],
+                        0,
+                    )?;
+                    region
+                        .assign_fixed(
+                            || "round constant",
+                            config.round_constants[i],
+                            0,
+                            || Ok(round_constants[i]),
+                        )
+                        .map(|_| ())
+                })?;
+
+                let value =
+                    state_value(state).map(|x| concrete::<F, P>(x, round));
+                self.assign_state(&mut region, 1, value)
+            },
+        )
+    }
+
+    /// Brick layer
+    fn brick(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedCell<F, F>; 3],
+    ) -> Result<[AssignedCell<F, F>; 3], Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "brick",
+            |mut region| {
+                config.q_brick.enable(&mut region, 0)?;
+                (0..3).try_for_each(|i| {
+                    state[i]
+                        .copy_advice(
+                            || "state",
+                            &mut region,
+                            config.state[i],
+                            0,
+                        )
+                        .map(|_| ())
+                })?;
+
+                let value = state_value(state).map(brick::<F, P>);
+                self.assign_state(&mut region, 1, value)
+            },
+        )
+    }
+
+    /// Bar function on a single element, over one row per digit from the
+    /// most significant one. The recomposition of the digits in the last row
+    /// is the input, and that of the outputs of the S-box is the output.
+    fn bar(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        let radices = P::radices();
+        let modulus_digits = P::modulus_digits();
+        let digits = input.value().map(|x| decomposition::<F, P>(x));
+
+        layouter.assign_region(
+            || "bar",
+            |mut region| {
+                config.q_bar_init.enable(&mut region, 0)?;
+                let mut composition = region.assign_advice(
+                    || "composition",
+                    config.composition,
+                    0,
+                    || Ok(F::zero()),
+                )?;
+                let mut output = region.assign_advice(
+                    || "output",
+                    config.output,
+                    0,
+                    || Ok(F::zero()),
+                )?;
+                region.assign_advice(
+                    || "smaller",
+                    config.smaller,
+                    0,
+                    || Ok(F::zero()),
+                )?;
+
+                let mut smaller = Some(false);
+                for (row, k) in (0..radices.len()).rev().enumerate() {
+                    let offset = row + 1;
+                    let (s_k, v_k) = (radices[k], modulus_digits[k]);
+                    let x = digits.as_ref().map(|digits| digits[k]);
+                    let y = x.map(s_box::<F, P>);
+                    smaller =
+                        smaller.zip(x).map(|(smaller, x)| smaller || x < v_k);
+
+                    config.q_bar.enable(&mut region, offset)?;
+                    region.assign_fixed(
+                        || "radix",
+                        config.radix,
+                        offset,
+                        || Ok(F::from(s_k)),
+                    )?;
+                    region.assign_fixed(
+                        || "modulus digit",
+                        config.modulus_digit,
+                        offset,
+                        || Ok(F::from(v_k)),
+                    )?;
+
+                    let mut assign =
+                        |name: &'static str, column, value: Option<u64>| {
+                            region.assign_advice(
+                                || name,
+                                column,
+                                offset,
+                                || value.map(F::from).ok_or(Error::Synthesis),
+                            )
+                        };
+                    assign("digit", config.digit, x)?;
+                    assign("image", config.image, y)?;
+                    assign("lt", config.lt, x.map(|x| (x < v_k) as u64))?;
+                    assign("gt", config.gt, x.map(|x| (x > v_k) as u64))?;
+                    assign(
+                        "smaller",
+                        config.smaller,
+                        smaller.map(|s| s as u64),
+                    )?;
+
+                    let radix = F::from(s_k);
+                    let value = composition
+                        .value()
+                        .zip(x)
+                        .map(|(acc, x)| *acc * radix + F::from(x));
+                    composition = region.assign_advice(
+                        || "composition",
+                        config.composition,
+                        offset,
+                        || value.ok_or(Error::Synthesis),
+                    )?;
+                    let value = output
+                        .value()
+                        .zip(y)
+                        .map(|(acc, y)| *acc * radix + F::from(y));
+                    output = region.assign_advice(
+                        || "output",
+                        config.output,
+                        offset,
+                        || value.ok_or(Error::Synthesis),
+                    )?;
+                }
+                region.constrain_equal(input.cell(), composition.cell())?;
+
+                Ok(output)
+            },
+        )
+    }
+}
+
+/// Value of the state, when it is known
+fn state_value<F: FieldExt>(state: &[AssignedCell<F, F>; 3]) -> Option<[F; 3]> {
+    Some([*state[0].value()?, *state[1].value()?, *state[2].value()?])
+}
+
+/// Circuit of the permutation of a private state, whose output is the public
+/// input
+#[derive(Debug, Clone)]
+pub struct PermutationCircuit<F: FieldExt, P: Parameters<F>> {
+    /// State to permute, known to the prover
+    pub input: Option<[F; 3]>,
+    _marker: PhantomData<P>,
+}
+
+impl<F: FieldExt, P: Parameters<F>> PermutationCircuit<F, P> {
+    pub fn new(input: Option<[F; 3]>) -> Self {
+        Self {
+            input,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: FieldExt, P: Parameters<F>> Circuit<F> for PermutationCircuit<F, P> {
+    type Config = (ReinforcedConcreteConfig<F>, Column<Instance>);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(None)
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let config = ReinforcedConcreteChip::<F, P>::configure(meta);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        (config, instance)
+    }
+
+    fn synthesize(
+        &self,
+        (config, instance): Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = ReinforcedConcreteChip::<F, P>::construct(config);
+        chip.load_tables(&mut layouter)?;
+
+        let input = layouter.assign_region(
+            || "input",
+            |mut region| chip.assign_state(&mut region, 0, self.input),
+        )?;
+        let output = chip.permutation(&mut layouter, input)?;
+        (0..3).try_for_each(|i| {
+            layouter.constrain_instance(output[i].cell(), instance, i)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pasta::Pasta;
+    use super::*;
+    use halo2_proofs::dev::MockProver;
+    use halo2_proofs::pasta::Fp;
+
+    /// Enough rows for the table of the S-box, of 1025 rows
+    const K: u32 = 11;
+
+    #[test]
+    fn test_permutation_circuit() {
+        let inputs = [
+            [Fp::zero(), Fp::one(), Fp::from(2)],
+            [-Fp::one(), -Fp::one(), -Fp::one()],
+        ];
+        inputs.iter().for_each(|input| {
+            let output = permutation::<Fp, Pasta>(*input);
+            let circuit = PermutationCircuit::<Fp, Pasta>::new(Some(*input));
+            let prover =
+                MockProver::run(K, &circuit, vec![output.to_vec()]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+        });
+    }
+
+    #[test]
+    fn test_permutation_circuit_wrong_output_fails() {
+        let input = [Fp::zero(), Fp::one(), Fp::from(2)];
+        let mut output = permutation::<Fp, Pasta>(input);
+        output[1] += Fp::one();
+
+        let circuit = PermutationCircuit::<Fp, Pasta>::new(Some(input));
+        let prover =
+            MockProver::run(K, &circuit, vec![output.to_vec()]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_decomposition() {
+        let x = Fp::from(123456789);
+        let digits = decomposition::<Fp, Pasta>(&x);
+        let recomposition = Pasta::radices()
+            .iter()
+            .zip(digits.iter())
+            .rev()
+            .fold(Fp::zero(), |acc, (&s, &x)| acc * Fp::from(s) + Fp::from(x));
+        assert_eq!(recomposition, x);
+        assert!(digits.iter().zip(Pasta::radices()).all(|(x, s)| x < s));
+    }
+}
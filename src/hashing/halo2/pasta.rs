@@ -0,0 +1,335 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Parameters of Reinforced Concrete over the base field of Pallas, which is
+//! the scalar field of Vesta.
+//!
+//! They follow the choices made for BLS12-381:
+//! - The concrete layer multiplies by circ(2, 1, 1), and the brick layer has
+//!   the same coefficients, since 5 is the smallest exponent coprime to p - 1
+//!   and both discriminants, -7, are non residues.
+//! - The radices are within 2^10 and close to p^(1/27), and V = 647 is the
+//!   largest prime for which a decomposition of p - 1 was found with every
+//!   digit at least V.
+//! - The S-box is a Fisher-Yates shuffle of the integers below V, drawing 16
+//!   bit little endian words, with rejection, from SHAKE128 of
+//!   "ReinforcedConcrete Pasta S-box".
+//! - The round constants are the successive 32 byte little endian words of
+//!   SHAKE128 of "ReinforcedConcrete Pasta round constants", with the top bit
+//!   cleared, that are smaller than p.
+//!
+//! The parameters are generated by `scripts/pasta_parameters.py`.
+
+use super::Parameters;
+use halo2_proofs::pasta::Fp;
+
+/// Parameters over the base field of Pallas
+#[derive(Debug, Clone, Copy)]
+pub struct Pasta;
+
+/// Radices `s_k` of the decomposition, from the least significant digit
+const RADICES: [u64; 27] = [
+    682, 671, 676, 659, 686, 705, 660, 689, 690, 683, 712, 687, 674, 678, 681,
+    680, 668, 679, 681, 685, 664, 688, 692, 673, 697, 650, 649,
+];
+
+/// Decomposition of p - 1 in the radices `s_k`
+const MODULUS_DIGITS: [u64; 27] = [
+    676, 654, 668, 656, 681, 687, 647, 656, 656, 673, 662, 652, 669, 654, 648,
+    673, 647, 662, 671, 656, 651, 683, 684, 658, 672, 648, 648,
+];
+
+/// S-box, a permutation of the integers below [`V`]
+const S_BOX: [u64; 647] = [
+    265, 53, 200, 325, 541, 197, 498, 46, 552, 249, 423, 334, 619, 120, 516,
+    365, 170, 15, 468, 168, 351, 113, 122, 11, 180, 67, 372, 477, 480, 227,
+    376, 260, 293, 286, 253, 431, 375, 210, 499, 257, 333, 553, 545, 318, 36,
+    578, 312, 485, 582, 313, 186, 138, 283, 347, 27, 461, 125, 472, 593, 620,
+    83, 393, 616, 392, 56, 380, 75, 102, 220, 373, 575, 73, 632, 76, 281, 567,
+    284, 7, 234, 476, 222, 386, 78, 402, 90, 80, 167, 3, 577, 108, 52, 208, 44,
+    94, 518, 317, 576, 28, 519, 495, 406, 256, 586, 382, 184, 421, 328, 579,
+    416, 469, 162, 544, 422, 185, 332, 331, 176, 23, 139, 362, 556, 635, 641,
+    370, 303, 604, 29, 394, 535, 344, 609, 119, 233, 397, 275, 487, 174, 459,
+    206, 48, 12, 396, 14, 104, 454, 152, 243, 550, 221, 111, 566, 526, 383, 97,
+    84, 543, 645, 269, 360, 43, 0, 147, 58, 307, 338, 217, 310, 560, 309, 16,
+    17, 5, 199, 630, 377, 155, 34, 494, 513, 432, 142, 204, 109, 137, 326, 246,
+    597, 157, 19, 361, 564, 617, 285, 276, 156, 296, 20, 282, 610, 467, 62, 24,
+    82, 562, 605, 452, 517, 636, 266, 589, 559, 209, 497, 212, 41, 95, 638,
+    273, 385, 502, 514, 262, 91, 442, 391, 236, 417, 144, 235, 241, 492, 573,
+    603, 145, 238, 319, 127, 32, 112, 437, 279, 25, 261, 161, 551, 460, 453,
+    390, 639, 430, 420, 521, 435, 403, 72, 625, 40, 489, 523, 320, 629, 59, 70,
+    389, 65, 271, 404, 348, 39, 181, 150, 424, 355, 299, 219, 642, 100, 359,
+    539, 158, 481, 232, 585, 292, 401, 405, 218, 116, 599, 409, 169, 258, 633,
+    101, 546, 290, 135, 211, 308, 441, 399, 68, 202, 367, 57, 192, 411, 418,
+    607, 554, 203, 205, 188, 470, 341, 50, 438, 242, 595, 627, 159, 154, 311,
+    427, 277, 301, 634, 415, 478, 126, 252, 259, 223, 248, 637, 323, 448, 99,
+    572, 45, 166, 289, 182, 226, 38, 195, 413, 506, 280, 324, 81, 465, 471,
+    230, 214, 505, 336, 463, 614, 611, 133, 434, 529, 87, 483, 189, 640, 61,
+    447, 322, 193, 329, 2, 503, 511, 490, 335, 558, 565, 177, 398, 130, 13,
+    352, 85, 74, 215, 531, 153, 164, 143, 129, 8, 443, 353, 160, 51, 343, 306,
+    428, 178, 412, 538, 473, 60, 618, 146, 216, 569, 179, 450, 509, 464, 123,
+    47, 410, 314, 455, 368, 548, 207, 528, 525, 175, 602, 92, 533, 364, 149,
+    395, 491, 315, 98, 225, 4, 508, 141, 88, 628, 606, 426, 316, 327, 350, 596,
+    574, 198, 77, 643, 250, 429, 251, 488, 512, 451, 622, 524, 580, 49, 268,
+    446, 496, 644, 433, 93, 388, 64, 462, 493, 590, 500, 263, 300, 337, 339,
+    557, 151, 21, 287, 601, 244, 358, 568, 110, 570, 294, 255, 18, 561, 132,
+    414, 106, 600, 9, 330, 190, 278, 486, 371, 587, 291, 270, 349, 124, 114,
+    482, 340, 140, 42, 79, 37, 66, 302, 504, 96, 594, 419, 115, 172, 440, 297,
+    201, 608, 54, 134, 321, 231, 71, 400, 26, 304, 381, 631, 107, 457, 501,
+    369, 295, 555, 356, 173, 298, 384, 247, 530, 6, 522, 445, 613, 532, 272,
+    148, 456, 466, 537, 121, 363, 542, 342, 439, 374, 128, 534, 224, 105, 547,
+    479, 10, 510, 581, 288, 187, 228, 354, 254, 22, 408, 196, 515, 103, 163,
+    624, 536, 425, 63, 612, 623, 131, 213, 366, 267, 378, 35, 237, 305, 592,
+    117, 274, 229, 571, 444, 191, 239, 165, 474, 484, 520, 475, 615, 584, 387,
+    583, 407, 588, 171, 264, 194, 55, 183, 86, 345, 240, 527, 136, 118, 598,
+    89, 30, 245, 33, 346, 626, 436, 563, 1, 379, 357, 591, 507, 458, 549, 449,
+    621, 31, 69, 646, 540,
+];
+
+/// Round constants, as canonical little endian limbs
+const ROUND_CONSTANTS: [[[u64; 4]; 3]; 6] = [
+    [
+        [
+            0xb5f301692e84b4e4,
+            0xc46aedf23218096f,
+            0x21fdffbdbeedad4d,
+            0x194727aa761becf0,
+        ],
+        [
+            0xcf22a5b85438365c,
+            0xe4bbba0dfbbde42f,
+            0xd07333a68574824b,
+            0x1621d0c27a496d30,
+        ],
+        [
+            0x455fa98d69c18a8a,
+            0x860b3c71e7a7bbc3,
+            0x8a62133fdb51b243,
+            0x2b0c0d9ec3fe8345,
+        ],
+    ],
+    [
+        [
+            0xfdd772444e142dc2,
+            0x093af25a3d407cab,
+            0x734ab0bd657e6c34,
+            0x11e0973705352b38,
+        ],
+        [
+            0x7ab98d239949eb56,
+            0x69314b941d7b5fd5,
+            0xebe8b3189c3a55bf,
+            0x3613a7b51cf4f5f7,
+        ],
+        [
+            0x5fb791796a605627,
+            0xeafe1ab26cf1eb30,
+            0x8031f6a70ebe65af,
+            0x2269dc53fb4247d2,
+        ],
+    ],
+    [
+        [
+            0x19124d2835b2b84e,
+            0x9243c26b5eae4189,
+            0xc32517ab91be1343,
+            0x2e76943f87abbbf3,
+        ],
+        [
+            0x67f86602efdf62b9,
+            0xaf90eede5768d38f,
+            0x99c8697f0cc051cd,
+            0x3fd86e32bd8cf379,
+        ],
+        [
+            0xa8a92c9297105a02,
+            0xd856fab0420a45cc,
+            0x80b77c19ddf41c46,
+            0x05903f4e2fd29639,
+        ],
+    ],
+    [
+        [
+            0x46d62e853bbebe69,
+            0x5de22c0709a9c133,
+            0x0b375caf9f362c78,
+            0x3a215acd481bd8e9,
+        ],
+        [
+            0xd50184ca3a486211,
+            0xfe4cb371d57dcb94,
+            0xc6c8beebbfe01e53,
+            0x3b3aaa9ec82025e8,
+        ],
+        [
+            0xf31cc7bdce0e850e,
+            0x4228ce88cfd3ebd3,
+            0xdc14d59bcd146f98,
+            0x2f2578bc190b32c0,
+        ],
+    ],
+    [
+        [
+            0xac2bba3fcf5e1778,
+            0xa0d50f47ddc8a276,
+            0xb5b0b6c6538726c0,
+            0x1d86bc50b975f62f,
+        ],
+        [
+            0x56d1e26e7ebf4bbf,
+            0xacf9c25c7410d496,
+            0x22bf578a17e09deb,
+            0x325ba311a6c431b1,
+        ],
+        [
+            0x6fb41626a809d64a,
+            0x16030e7391fad989,
+            0x6b1b42f6df5c8711,
+            0x391d0c53e0df3ae6,
+        ],
+    ],
+    [
+        [
+            0x8118d659fb9b8792,
+            0x62d1035247dcd734,
+            0x0fdf4a883648b63f,
+            0x03e58375685cf64c,
+        ],
+        [
+            0x6b7da3db94fb4294,
+            0xb66b85c8ef3147e8,
+            0x3f5e434d0cb7d3c0,
+            0x0331f996fc561307,
+        ],
+        [
+            0x62bc19cf40e862e9,
+            0x3456ce9387d8ad44,
+            0x6d74becde7211e6d,
+            0x1e6844f7c0fd242d,
+        ],
+    ],
+];
+
+impl Parameters<Fp> for Pasta {
+    const V: u64 = 647;
+
+    fn radices() -> &'static [u64] {
+        &RADICES
+    }
+
+    fn modulus_digits() -> &'static [u64] {
+        &MODULUS_DIGITS
+    }
+
+    fn s_box() -> &'static [u64] {
+        &S_BOX
+    }
+
+    fn matrix() -> [[Fp; 3]; 3] {
+        let (one, two) = (Fp::one(), Fp::from(2));
+        [[two, one, one], [one, two, one], [one, one, two]]
+    }
+
+    fn round_constants() -> [[Fp; 3]; 6] {
+        let mut round_constants = [[Fp::zero(); 3]; 6];
+        (0..6).for_each(|round| {
+            (0..3).for_each(|i| {
+                round_constants[round][i] =
+                    Fp::from_raw(ROUND_CONSTANTS[round][i]);
+            })
+        });
+
+        round_constants
+    }
+
+    fn brick_coefficients() -> [[Fp; 2]; 2] {
+        [[Fp::one(), Fp::from(2)], [Fp::from(3), Fp::from(4)]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashing::halo2::{bar, decomposition, permutation, s_box};
+    use halo2_proofs::pasta::group::ff::Field;
+
+    #[test]
+    fn test_pasta_parameters() {
+        // V is a prime below every radix and every digit of p - 1
+        assert!((2..Pasta::V).all(|d| Pasta::V % d != 0));
+        assert!(RADICES.iter().all(|&s| Pasta::V < s && s <= 1 << 10));
+        assert!(MODULUS_DIGITS.iter().all(|&v| Pasta::V <= v));
+        assert_eq!(decomposition::<Fp, Pasta>(&-Fp::one()), MODULUS_DIGITS);
+
+        // The S-box is a permutation
+        let mut sorted = S_BOX;
+        sorted.sort_unstable();
+        assert!(sorted.iter().enumerate().all(|(x, &y)| x as u64 == y));
+
+        // The brick layer is a permutation: x^5 is one as gcd(5, p - 1) = 1,
+        // and 5 is the smallest such exponent
+        let modulus_minus_one_mod = |m: u64| {
+            (0..27)
+                .rev()
+                .fold(0, |acc, k| (acc * RADICES[k] + MODULUS_DIGITS[k]) % m)
+        };
+        assert_ne!(modulus_minus_one_mod(5), 0);
+        assert_eq!(modulus_minus_one_mod(2), 0);
+        assert_eq!(modulus_minus_one_mod(3), 0);
+        Pasta::brick_coefficients()
+            .iter()
+            .for_each(|[alpha, beta]| {
+                let discriminant = alpha.square() - Fp::from(4) * beta;
+                assert!(bool::from(discriminant.sqrt().is_none()));
+            });
+    }
+
+    #[test]
+    fn test_bar_stays_in_field() {
+        // The outputs of the S-box recompose below p, so they are the digits
+        // of the output
+        [-Fp::one(), -Fp::from(5), Fp::from(123456789)]
+            .iter()
+            .for_each(|x| {
+                let images: Vec<u64> = decomposition::<Fp, Pasta>(x)
+                    .into_iter()
+                    .map(s_box::<Fp, Pasta>)
+                    .collect();
+                let output = bar::<Fp, Pasta>(x);
+                assert_eq!(decomposition::<Fp, Pasta>(&output), images);
+            });
+    }
+
+    #[test]
+    fn test_pasta_permutation() {
+        // Test vector from the independent implementation of the parameters
+        // in scripts/pasta_parameters.py, which also generates the S-box, the
+        // round constants and the radices
+        let output =
+            permutation::<Fp, Pasta>([Fp::zero(), Fp::one(), Fp::from(2)]);
+        let expected = [
+            Fp::from_raw([
+                0xdf4184ccddf18ca6,
+                0xdf73fbd5fee750b0,
+                0xbe2e9fdb5de75dff,
+                0x16d133ca11a9d5a2,
+            ]),
+            Fp::from_raw([
+                0xb7bee2d6961a99e3,
+                0x917fefa1a545a0db,
+                0xa084de02aae0c87e,
+                0x27f81e903e7960b2,
+            ]),
+            Fp::from_raw([
+                0x9309199b28c757eb,
+                0xc70631b43fb3a9f3,
+                0x8b4a10a087642658,
+                0x014445a98b7b732a,
+            ]),
+        ];
+        assert_eq!(output, expected);
+    }
+}
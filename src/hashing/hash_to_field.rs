@@ -0,0 +1,225 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Hashing of byte strings to elements of the scalar field, in the spirit of
+//! `hash_to_field` from RFC 9380, with the Zelbet sponge in place of the
+//! SHA-2 based `expand_message`.
+//!
+//! The domain separation tag, the message and the number of outputs are
+//! absorbed as `[dst, msg, count]`, with both byte strings encoded by
+//! [`bytes_to_scalars`], and the outputs are squeezed from the sponge. The RFC
+//! expands to 64 bytes per element and reduces them modulo p to make the bias
+//! negligible; the sponge outputs field elements directly, so they are uniform
+//! as long as the permutation behaves randomly and no reduction is needed.
+
+use super::sponge::{
+    bytes_to_scalars, domain_tag, sponge_zelbet_domain_gadget,
+    sponge_zelbet_domain_out_of,
+};
+use super::zelbet::ZelbetConstants;
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Label of the sponge domain used for hashing to the field
+pub const HASH_TO_FIELD_DOMAIN: &[u8] = b"rc.h2f";
+
+/// Hash a message to `count` field elements under the domain separation tag
+/// (out of circuit). The tag must not be empty.
+pub fn hash_to_field(dst: &[u8], msg: &[u8], count: usize) -> Vec<BlsScalar> {
+    assert!(!dst.is_empty(), "the domain separation tag is empty");
+    let mut input = bytes_to_scalars(dst);
+    input.extend(bytes_to_scalars(msg));
+    input.push(BlsScalar::from(count as u64));
+    sponge_zelbet_domain_out_of(domain_tag(HASH_TO_FIELD_DOMAIN), input, count)
+}
+
+/// Hash a message given as byte variables to `count` field elements in
+/// circuit, where the bytes of the message are also given as values. Every
+/// byte is range checked, and packed into chunks as in [`bytes_to_scalars`].
+/// The tag and the length of the message are part of the circuit description.
+pub fn hash_to_field_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    dst: &[u8],
+    msg: &[Variable],
+    msg_bytes: &[u8],
+    count: usize,
+) -> Vec<Variable> {
+    assert!(!dst.is_empty(), "the domain separation tag is empty");
+    assert_eq!(msg.len(), msg_bytes.len());
+    let mut input_values = bytes_to_scalars(dst);
+    input_values.extend(bytes_to_scalars(msg_bytes));
+    input_values.push(BlsScalar::from(count as u64));
+
+    let mut input: Vec<Variable> = bytes_to_scalars(dst)
+        .into_iter()
+        .map(|element| composer.add_witness_to_circuit_description(element))
+        .collect();
+
+    input.push(
+        composer.add_witness_to_circuit_description(BlsScalar::from(
+            msg.len() as u64
+        )),
+    );
+    msg.iter().for_each(|byte| composer.range_gate(*byte, 8));
+    msg.chunks(31).for_each(|chunk| {
+        let mut power = BlsScalar::one();
+        let packed = chunk[1..].iter().fold(chunk[0], |packed, byte| {
+            power *= BlsScalar::from(256);
+            composer.big_add(
+                (BlsScalar::one(), packed),
+                (power, *byte),
+                None,
+                BlsScalar::zero(),
+                None,
+            )
+        });
+        input.push(packed);
+    });
+
+    input.push(
+        composer
+            .add_witness_to_circuit_description(BlsScalar::from(count as u64)),
+    );
+    sponge_zelbet_domain_gadget(
+        composer,
+        constants,
+        domain_tag(HASH_TO_FIELD_DOMAIN),
+        input,
+        &input_values,
+        count,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+
+    #[test]
+    fn test_hash_to_field_vectors() {
+        assert_eq!(
+            hash_to_field(b"rc-test", b"abc", 2),
+            vec![
+                BlsScalar([
+                    3343039731652257539,
+                    14572245988733812788,
+                    14288354800322047077,
+                    6127368940757373781,
+                ]),
+                BlsScalar([
+                    12698308436927748271,
+                    2424271689715779897,
+                    6968280297993740863,
+                    3325676052039858919,
+                ]),
+            ]
+        );
+        assert_eq!(
+            hash_to_field(b"rc-test", b"", 1),
+            vec![BlsScalar([
+                15891755868345431771,
+                9612515219219509305,
+                9840791447979591682,
+                7972875577860346236,
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_hash_to_field_separation() {
+        let reference = hash_to_field(b"rc-test", b"abc", 2);
+        assert_ne!(reference, hash_to_field(b"rc-other", b"abc", 2));
+        assert_ne!(reference, hash_to_field(b"rc-test", b"abd", 2));
+        assert_ne!(reference, hash_to_field(b"rc-test", b"abc\0", 2));
+        assert_ne!(reference, hash_to_field(b"rc-tes", b"tabc", 2));
+
+        // The number of outputs is bound, so requesting fewer elements does
+        // not give a prefix of the longer output
+        assert_ne!(reference[0], hash_to_field(b"rc-test", b"abc", 1)[0]);
+
+        // Messages spanning several chunks
+        let long = [7u8; 70];
+        assert_ne!(
+            hash_to_field(b"rc-test", &long, 1),
+            hash_to_field(b"rc-test", &long[..69], 1)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_to_field_empty_dst() {
+        hash_to_field(b"", b"abc", 1);
+    }
+
+    #[test]
+    fn test_hash_to_field_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let msg: Vec<Variable> = b"abc"
+                    .iter()
+                    .map(|byte| {
+                        composer.add_input(BlsScalar::from(*byte as u64))
+                    })
+                    .collect();
+                let output = hash_to_field_gadget(
+                    composer, &constants, b"rc-test", &msg, b"abc", 2,
+                );
+
+                let expected = hash_to_field(b"rc-test", b"abc", 2);
+                output.iter().zip(expected.iter()).for_each(|(o, e)| {
+                    composer.constrain_to_constant(*o, *e, None);
+                });
+
+                pad_lookup_gates(composer);
+            },
+            5000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_hash_to_field_gadget_non_byte_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                // The witness packs to the same chunk as the bytes [1, 1], but
+                // its first element is not a byte
+                let msg = [
+                    composer.add_input(BlsScalar::from(257)),
+                    composer.add_input(BlsScalar::zero()),
+                ];
+                let output = hash_to_field_gadget(
+                    composer,
+                    &constants,
+                    b"rc-test",
+                    &msg,
+                    &[1, 1],
+                    1,
+                );
+                composer.constrain_to_constant(
+                    output[0],
+                    hash_to_field(b"rc-test", &[1, 1], 1)[0],
+                    None,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            5000,
+        );
+        assert!(res.is_err());
+    }
+}
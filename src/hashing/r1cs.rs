@@ -0,0 +1,380 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! R1CS gadgets for the Zelbet permutation and sponge over the BLS12-381
+//! scalar field, for circuits written with arkworks. They are enabled by the
+//! `arkworks` feature, and give the same outputs as the native functions.
+//!
+//! Without lookups the bar layer is the expensive one. Each digit is range
+//! checked through its bits, which also select the output of the S-box from a
+//! table of constants, and the comparison with the digits of p - 1 follows
+//! that of [`bar_arithmetic_gadget`]. A bar costs about 15 000 constraints,
+//! and a permutation about 45 000.
+//!
+//! [`bar_arithmetic_gadget`]: crate::hashing::gadgets::bar_arithmetic_gadget
+
+use crate::constants::{
+    BLS_SCALAR_REAL, CONSTANTS_BLS, DECOMPOSITION_S_I, MATRIX_BLS,
+    MONTGOMERY_FOUR, MONTGOMERY_THREE, MONTGOMERY_TWO, SBOX_MONTGOMERY,
+};
+use crate::hashing::zelbet::{bar, decomposition};
+use ark_bls12_381::Fr;
+use ark_ff::{BigInteger, PrimeField};
+use ark_r1cs_std::{
+    alloc::AllocVar, boolean::Boolean, eq::EqGadget, fields::fp::FpVar,
+    fields::FieldVar, select::CondSelectGadget, R1CSVar,
+};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystemRef, SynthesisError,
+};
+use dusk_bytes::Serializable;
+use dusk_plonk::prelude::BlsScalar;
+
+/// Number of bits of the range checks, enough for every radix `s_k`
+const DIGIT_BITS: usize = 10;
+
+/// Convert a scalar to the arkworks representation of the same element
+pub fn to_fr(scalar: &BlsScalar) -> Fr {
+    Fr::from_le_bytes_mod_order(&scalar.to_bytes())
+}
+
+/// Convert an arkworks field element to a scalar
+pub fn from_fr(element: &Fr) -> BlsScalar {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&element.into_repr().to_bytes_le());
+    BlsScalar::from_bytes(&bytes).expect("a field element is canonical")
+}
+
+/// Concrete layer, a multiplication by the MDS matrix and the addition of the
+/// round constants, which is linear and adds no constraint
+pub fn concrete_gadget(state: &[FpVar<Fr>; 3], round: usize) -> [FpVar<Fr>; 3] {
+    let mut new_state = [
+        FpVar::constant(to_fr(&CONSTANTS_BLS[round][0])),
+        FpVar::constant(to_fr(&CONSTANTS_BLS[round][1])),
+        FpVar::constant(to_fr(&CONSTANTS_BLS[round][2])),
+    ];
+    (0..3).for_each(|i| {
+        (0..3).for_each(|j| {
+            new_state[i] += &state[j] * to_fr(&MATRIX_BLS[i][j]);
+        });
+    });
+
+    new_state
+}
+
+/// Brick layer, with 5 constraints
+pub fn brick_gadget(
+    state: &[FpVar<Fr>; 3],
+) -> Result<[FpVar<Fr>; 3], SynthesisError> {
+    let x_squared = state[0].square()?;
+    let y_1 = x_squared.square()? * &state[0];
+    let y_2 = &state[1] * (&x_squared + &state[0] + to_fr(&MONTGOMERY_TWO));
+    let y_3 = &state[2]
+        * (state[1].square()?
+            + &state[1] * to_fr(&MONTGOMERY_THREE)
+            + to_fr(&MONTGOMERY_FOUR));
+
+    Ok([y_1, y_2, y_3])
+}
+
+/// Little endian bits of a value, allocated as witnesses
+fn bits_gadget(
+    cs: &ConstraintSystemRef<Fr>,
+    value: Option<u64>,
+) -> Result<Vec<Boolean<Fr>>, SynthesisError> {
+    (0..DIGIT_BITS)
+        .map(|i| {
+            Boolean::new_witness(cs.clone(), || {
+                value
+                    .map(|v| (v >> i) & 1 == 1)
+                    .ok_or(SynthesisError::AssignmentMissing)
+            })
+        })
+        .collect()
+}
+
+/// Output of the S-box on every value of [`DIGIT_BITS`] bits, the identity
+/// from 659 on
+fn s_box_table() -> Vec<FpVar<Fr>> {
+    (0..1 << DIGIT_BITS)
+        .map(|x| match x < SBOX_MONTGOMERY.len() {
+            true => FpVar::constant(to_fr(&SBOX_MONTGOMERY[x])),
+            false => FpVar::constant(Fr::from(x as u64)),
+        })
+        .collect()
+}
+
+/// Bar function, for a single element of the state. For every digit `x_k`,
+/// from the most significant one:
+///
+/// 1. The bits of `x_k` and of `s_k - 1 - x_k` are allocated, so that `0 <= x_k
+///    < s_k`.
+/// 2. The bits of `x_k` select its image by the S-box from a table of
+///    constants.
+/// 3. The booleans `lt` and `gt` compare `x_k` with `v_k`. At most one is set,
+///    `x_k = v_k` when neither is, and the bits of `lt·(v_k - 1 - x_k) +
+///    gt·(x_k - v_k - 1)` check the inequality they claim. `gt` is only allowed
+///    once a digit was smaller, so the decomposition is at most that of p - 1.
+///
+/// The digits recompose to the input, and the outputs of the S-box to the
+/// output.
+pub fn bar_gadget(input: &FpVar<Fr>) -> Result<FpVar<Fr>, SynthesisError> {
+    if input.is_constant() {
+        let mut state = [from_fr(&input.value()?); 3];
+        bar(&mut state);
+        return Ok(FpVar::constant(to_fr(&state[0])));
+    }
+
+    let cs = input.cs();
+    let digits = input.value().ok().map(|v| decomposition(&from_fr(&v)));
+    let table = s_box_table();
+
+    let mut composition = FpVar::zero();
+    let mut output = FpVar::zero();
+    let mut smaller = Boolean::FALSE;
+    for k in (0..27).rev() {
+        let value = digits.map(|d| d[k]);
+        let s_k = DECOMPOSITION_S_I[k].0[0];
+        let v_k = BLS_SCALAR_REAL[k].0[0];
+
+        // 1. Range of the digit
+        let bits = bits_gadget(&cs, value)?;
+        let x = Boolean::le_bits_to_fp_var(&bits)?;
+        let bound = bits_gadget(&cs, value.map(|x| s_k - 1 - x))?;
+        Boolean::le_bits_to_fp_var(&bound)?
+            .enforce_equal(&(FpVar::constant(Fr::from(s_k - 1)) - &x))?;
+
+        // 2. S-box, selected with the bits in big endian order
+        let position: Vec<Boolean<Fr>> = bits.iter().rev().cloned().collect();
+        let y =
+            FpVar::conditionally_select_power_of_two_vector(&position, &table)?;
+
+        // 3. Comparison with the digit of p - 1
+        let lt = Boolean::new_witness(cs.clone(), || {
+            value
+                .map(|x| x < v_k)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let gt = Boolean::new_witness(cs.clone(), || {
+            value
+                .map(|x| x > v_k)
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        lt.and(&gt)?.enforce_equal(&Boolean::FALSE)?;
+        x.conditional_enforce_equal(
+            &FpVar::constant(Fr::from(v_k)),
+            &lt.or(&gt)?.not(),
+        )?;
+
+        let distance = FpVar::from(lt.clone())
+            * (FpVar::constant(Fr::from(v_k - 1)) - &x)
+            + FpVar::from(gt.clone())
+                * (&x - FpVar::constant(Fr::from(v_k + 1)));
+        let distance_value = value.map(|x| match x < v_k {
+            true => v_k - 1 - x,
+            false => x.saturating_sub(v_k + 1),
+        });
+        Boolean::le_bits_to_fp_var(&bits_gadget(&cs, distance_value)?)?
+            .enforce_equal(&distance)?;
+
+        gt.and(&smaller.not())?.enforce_equal(&Boolean::FALSE)?;
+        smaller = smaller.or(&lt)?;
+
+        composition = composition * Fr::from(s_k) + &x;
+        output = output * Fr::from(s_k) + &y;
+    }
+    composition.enforce_equal(input)?;
+
+    Ok(output)
+}
+
+/// Zelbet permutation, with the same layers as `zelbet_out_of_circuit`
+pub fn zelbet_gadget(
+    state: &[FpVar<Fr>; 3],
+) -> Result<[FpVar<Fr>; 3], SynthesisError> {
+    let mut state = concrete_gadget(state, 0);
+    state = brick_gadget(&state)?;
+    state = concrete_gadget(&state, 1);
+    state = brick_gadget(&state)?;
+    state = concrete_gadget(&state, 2);
+    state = brick_gadget(&state)?;
+    state = concrete_gadget(&state, 3);
+    state = [
+        bar_gadget(&state[0])?,
+        bar_gadget(&state[1])?,
+        bar_gadget(&state[2])?,
+    ];
+    state = concrete_gadget(&state, 4);
+    state = brick_gadget(&state)?;
+    state = concrete_gadget(&state, 5);
+    state = brick_gadget(&state)?;
+    state = concrete_gadget(&state, 4);
+    state = brick_gadget(&state)?;
+    Ok(concrete_gadget(&state, 3))
+}
+
+/// Sponge with the capacity initialised to one, as
+/// [`sponge_zelbet_out_of`](crate::hashing::sponge::sponge_zelbet_out_of)
+pub fn sponge_zelbet_gadget(
+    input: &[FpVar<Fr>],
+    length_out: usize,
+) -> Result<Vec<FpVar<Fr>>, SynthesisError> {
+    sponge_zelbet_domain_gadget(BlsScalar::one(), input, length_out)
+}
+
+/// Sponge with the capacity initialised to the given domain, as
+/// [`sponge_zelbet_domain_out_of`](crate::hashing::sponge::sponge_zelbet_domain_out_of)
+pub fn sponge_zelbet_domain_gadget(
+    domain: BlsScalar,
+    input: &[FpVar<Fr>],
+    length_out: usize,
+) -> Result<Vec<FpVar<Fr>>, SynthesisError> {
+    // Pad the input to a multiple of two
+    let mut input = input.to_vec();
+    if input.len() % 2 == 1 {
+        input.push(FpVar::one());
+    }
+
+    let mut state = zelbet_gadget(&[
+        input[0].clone(),
+        input[1].clone(),
+        FpVar::constant(to_fr(&domain)),
+    ])?;
+    for k in 1..input.len() / 2 {
+        state[0] += &input[2 * k];
+        state[1] += &input[2 * k + 1];
+        state = zelbet_gadget(&state)?;
+    }
+
+    let mut output = vec![state[0].clone(); length_out];
+    if length_out > 1 {
+        output[1] = state[1].clone();
+    }
+    for k in 1..length_out / 2 {
+        state = zelbet_gadget(&state)?;
+        output[2 * k] = state[0].clone();
+        output[2 * k + 1] = state[1].clone();
+    }
+    if length_out % 2 == 1 && length_out > 1 {
+        state = zelbet_gadget(&state)?;
+        output[length_out - 1] = state[0].clone();
+    }
+
+    Ok(output)
+}
+
+/// Example circuit proving the knowledge of a preimage of the given length
+/// for a sponge hash, which is the only public input. The preimage is `None`
+/// when generating the parameters. It is proven with Groth16 in the
+/// `groth16_sponge_preimage` example.
+#[derive(Debug, Clone)]
+pub struct SpongePreimageCircuit {
+    /// Length of the preimage
+    pub length: usize,
+    /// Preimage, known to the prover
+    pub preimage: Option<Vec<BlsScalar>>,
+    /// Hash of the preimage
+    pub hash: BlsScalar,
+}
+
+impl ConstraintSynthesizer<Fr> for SpongePreimageCircuit {
+    fn generate_constraints(
+        self,
+        cs: ConstraintSystemRef<Fr>,
+    ) -> Result<(), SynthesisError> {
+        let hash = FpVar::new_input(cs.clone(), || Ok(to_fr(&self.hash)))?;
+        let preimage = (0..self.length)
+            .map(|i| {
+                FpVar::new_witness(cs.clone(), || {
+                    self.preimage
+                        .as_ref()
+                        .map(|preimage| to_fr(&preimage[i]))
+                        .ok_or(SynthesisError::AssignmentMissing)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        sponge_zelbet_gadget(&preimage, 1)?[0].enforce_equal(&hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashing::sponge::sponge_zelbet_out_of;
+    use crate::hashing::zelbet::zelbet_out_of_circuit;
+    use ark_relations::r1cs::ConstraintSystem;
+
+    #[test]
+    fn test_field_conversion() {
+        let scalar = -BlsScalar::from(5);
+        assert_eq!(to_fr(&scalar), -Fr::from(5u64));
+        assert_eq!(from_fr(&to_fr(&scalar)), scalar);
+    }
+
+    #[test]
+    fn test_zelbet_gadget() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let input = [BlsScalar::one(), -BlsScalar::from(5), BlsScalar::from(7)];
+        let state = [
+            FpVar::new_witness(cs.clone(), || Ok(to_fr(&input[0]))).unwrap(),
+            FpVar::new_witness(cs.clone(), || Ok(to_fr(&input[1]))).unwrap(),
+            FpVar::new_witness(cs.clone(), || Ok(to_fr(&input[2]))).unwrap(),
+        ];
+
+        let output = zelbet_gadget(&state).unwrap();
+        let expected = zelbet_out_of_circuit(input);
+        (0..3).for_each(|i| {
+            assert_eq!(from_fr(&output[i].value().unwrap()), expected[i]);
+        });
+        assert!(cs.num_constraints() < 50_000);
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_bar_gadget() {
+        let scalar = -BlsScalar::from(5);
+        let mut expected = [scalar; 3];
+        bar(&mut expected);
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let input = FpVar::new_witness(cs.clone(), || Ok(to_fr(&scalar)));
+        let output = bar_gadget(&input.unwrap()).unwrap();
+        assert_eq!(from_fr(&output.value().unwrap()), expected[0]);
+        assert!(cs.is_satisfied().unwrap());
+
+        // A constant input gives a constant output
+        let output = bar_gadget(&FpVar::constant(to_fr(&scalar))).unwrap();
+        assert!(output.is_constant());
+        assert_eq!(from_fr(&output.value().unwrap()), expected[0]);
+
+        // Any other output is rejected
+        let wrong = FpVar::new_witness(cs.clone(), || {
+            Ok(to_fr(&expected[0]) + Fr::from(1u64))
+        })
+        .unwrap();
+        output.enforce_equal(&wrong).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_sponge_zelbet_gadget() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let input: Vec<BlsScalar> =
+            (1..6).map(|x| BlsScalar::from(x as u64)).collect();
+        let variables: Vec<FpVar<Fr>> = input
+            .iter()
+            .map(|x| FpVar::new_witness(cs.clone(), || Ok(to_fr(x))).unwrap())
+            .collect();
+
+        let output = sponge_zelbet_gadget(&variables, 3).unwrap();
+        let expected = sponge_zelbet_out_of(input, 3);
+        (0..3).for_each(|i| {
+            assert_eq!(from_fr(&output[i].value().unwrap()), expected[i]);
+        });
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
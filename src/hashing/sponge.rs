@@ -4,98 +4,200 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::constants::{MONTGOMERY_TWO, S_I_DECOMPOSITION_MONTGOMERY};
-
 pub use super::zelbet::*;
-use crate::constants::CONSTANTS_BLS;
 use dusk_bytes::*;
 use dusk_plonk::{
     constraint_system::{StandardComposer, Variable},
     prelude::BlsScalar,
 };
 
+/// Domain tag for the sponge derived from a non empty label of at most 31
+/// bytes, by reading the label as a little endian integer and setting the last
+/// byte to its length. Distinct labels give distinct tags, even when they end
+/// in zero bytes, and every tag is at least 2^248, so none of them is equal to
+/// the capacity of one used by the plain sponge.
+pub fn domain_tag(label: &[u8]) -> BlsScalar {
+    assert!(label.len() < 32, "domain labels are at most 31 bytes");
+    assert!(!label.is_empty(), "domain labels are not empty");
+    let mut bytes = [0u8; 32];
+    bytes[..label.len()].copy_from_slice(label);
+    bytes[31] = label.len() as u8;
+    BlsScalar::from_bytes(&bytes).expect("a tag below 2^253 is canonical")
+}
+
+/// Whether the scalar is the [`domain_tag`] of some label, which is the case
+/// when its last byte is a length between 1 and 31 and the bytes between the
+/// label and its length are zero
+pub fn is_domain_tag(scalar: &BlsScalar) -> bool {
+    let bytes = scalar.to_bytes();
+    let length = bytes[31] as usize;
+    (1..32).contains(&length) && bytes[length..31].iter().all(|b| *b == 0)
+}
+
+/// Encode a byte string as field elements, giving its length followed by the
+/// bytes in chunks of 31, each read as a little endian integer. The encoding
+/// is injective, and prefix free thanks to the length.
+pub fn bytes_to_scalars(bytes: &[u8]) -> Vec<BlsScalar> {
+    let mut scalars = vec![BlsScalar::from(bytes.len() as u64)];
+    bytes.chunks(31).for_each(|chunk| {
+        let mut chunk_bytes = [0u8; 32];
+        chunk_bytes[..chunk.len()].copy_from_slice(chunk);
+        scalars.push(
+            BlsScalar::from_bytes(&chunk_bytes)
+                .expect("a 31 byte integer is canonical"),
+        );
+    });
+    scalars
+}
+
+/// Sponge input `[first, message length, message...]`, where the first element
+/// is a key, a blinding factor or a domain tag. Binding the length keeps the
+/// inputs of messages of different lengths apart, which the padding of the
+/// sponge alone does not.
+pub fn length_prefixed_input(
+    first: BlsScalar,
+    message: &[BlsScalar],
+) -> Vec<BlsScalar> {
+    let mut input = vec![first, BlsScalar::from(message.len() as u64)];
+    input.extend_from_slice(message);
+    input
+}
+
+/// In circuit [`length_prefixed_input`], where the first element and the
+/// message are given as variables along with their values. The length is
+/// added to the circuit description, and the input is returned as variables
+/// along with their values.
+pub fn length_prefixed_input_gadget(
+    composer: &mut StandardComposer,
+    first: Variable,
+    first_value: BlsScalar,
+    message: &[Variable],
+    message_values: &[BlsScalar],
+) -> (Vec<Variable>, Vec<BlsScalar>) {
+    assert_eq!(message.len(), message_values.len());
+    let input_values = length_prefixed_input(first_value, message_values);
+    let length = composer.add_witness_to_circuit_description(input_values[1]);
+    let mut input = vec![first, length];
+    input.extend_from_slice(message);
+    (input, input_values)
+}
+
 /// Sponge design for in circuit reinforced concrete, with arbitrary length
 /// input and output. Input length is read by the function, and output length is
-/// an input parameter.
+/// an input parameter. The input is given as variables along with their
+/// values.
 pub fn sponge_zelbet_gadget(
     composer: &mut StandardComposer,
     input: Vec<Variable>,
+    input_values: &[BlsScalar],
     length_out: usize,
 ) -> Vec<Variable> {
     // These constants are needed in reinforced concrete, so they are recorded
-    // as variables here for efficiency
-    let zero = composer.add_witness_to_circuit_description(BlsScalar::zero());
-    let one = composer.add_witness_to_circuit_description(BlsScalar::one());
-    let two = composer.add_witness_to_circuit_description(MONTGOMERY_TWO);
-    // We don't want to add the constants for the concrete gadget as inputs for
-    // each call of zelbet, so we add them once in the sponge
-    let mut constants_for_rounds = [one; 18];
-    (0..6).for_each(|k| {
-        (0..3).for_each(|j| {
-            constants_for_rounds[3 * k + j] = composer
-                .add_witness_to_circuit_description(CONSTANTS_BLS[k][j]);
-        })
-    });
+    // as variables here for efficiency, and shared by every call of zelbet
+    let constants = ZelbetConstants::new(composer);
+    sponge_gadget(
+        composer,
+        &constants,
+        (constants.one, BlsScalar::one()),
+        input,
+        input_values,
+        length_out,
+    )
+}
 
-    // Initialise the s_i values as variables
-    let mut s_i_decomposition = [input[0]; 27];
-    (0..27).for_each(|k| {
-        s_i_decomposition[k] = composer.add_witness_to_circuit_description(
-            S_I_DECOMPOSITION_MONTGOMERY[k],
-        );
-    });
+/// In circuit sponge with the capacity element initialised to the given
+/// domain, rather than to one, so that hashes computed for different purposes
+/// can never collide. The domain is added to the circuit description, see
+/// [`domain_tag`], and the input is given as variables along with their
+/// values.
+pub fn sponge_zelbet_domain_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    domain: BlsScalar,
+    input: Vec<Variable>,
+    input_values: &[BlsScalar],
+    length_out: usize,
+) -> Vec<Variable> {
+    let domain_variable = composer.add_witness_to_circuit_description(domain);
+    sponge_gadget(
+        composer,
+        constants,
+        (domain_variable, domain),
+        input,
+        input_values,
+        length_out,
+    )
+}
+
+/// In circuit sponge with the capacity element initialised to a secret key,
+/// given as a variable along with its value, which keys the sponge. A key of
+/// one or equal to a [`domain_tag`] gives the plain or a domain separated
+/// sponge, so callers must reject such keys.
+pub fn sponge_zelbet_keyed_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    key: Variable,
+    key_value: BlsScalar,
+    input: Vec<Variable>,
+    input_values: &[BlsScalar],
+    length_out: usize,
+) -> Vec<Variable> {
+    sponge_gadget(
+        composer,
+        constants,
+        (key, key_value),
+        input,
+        input_values,
+        length_out,
+    )
+}
+
+/// In circuit sponge with the capacity initialised to the given variable and
+/// its value
+fn sponge_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    domain: (Variable, BlsScalar),
+    input: Vec<Variable>,
+    input_values: &[BlsScalar],
+    length_out: usize,
+) -> Vec<Variable> {
+    assert_eq!(input.len(), input_values.len());
+    let one = constants.one;
 
     // Initialise input as mutable
     let mut input = input.clone();
+    let mut input_values = input_values.to_vec();
 
     // Determine input length to carry out correct number of rounds, and pad if
     // it is not a multiple of two
     let mut length_in = input.len();
     if length_in % 2 == 1 {
         input.push(one);
+        input_values.push(BlsScalar::one());
         length_in = length_in + 1;
     }
 
-    // Fixed starting constant values as defined in reinforced concrete paper
-    let mut state = [input[0], input[1], one];
+    // Starting values as defined in reinforced concrete paper, with the
+    // capacity set to the domain
+    let mut state = [input[0], input[1], domain.0];
+    let mut values = [input_values[0], input_values[1], domain.1];
 
-    state = zelbet_gadget(
-        composer,
-        &state,
-        s_i_decomposition,
-        constants_for_rounds,
-        zero,
-        one,
-        two,
-    );
+    state = constants.permute(composer, &state, &values);
+    values = zelbet_out_of_circuit(values);
 
     // Repeat cycle of adding the two relevant scalars together and then hashing
     (1..length_in / 2).for_each(|k| {
-        // Field addition of the two scalars together
-        state[0] = composer.big_add(
-            (BlsScalar::one(), state[0]),
-            (BlsScalar::one(), input[2 * k]),
-            None,
-            BlsScalar::zero(),
-            None,
-        );
-        state[1] = composer.big_add(
-            (BlsScalar::one(), state[1]),
-            (BlsScalar::one(), input[2 * k + 1]),
-            None,
-            BlsScalar::zero(),
-            None,
-        );
-        // Conduct the next round of hashing
-        state = zelbet_gadget(
+        absorb_gadget(
             composer,
-            &state,
-            s_i_decomposition,
-            constants_for_rounds,
-            zero,
-            one,
-            two,
+            &mut state,
+            &mut values,
+            [input[2 * k], input[2 * k + 1]],
+            [input_values[2 * k], input_values[2 * k + 1]],
         );
+        // Conduct the next round of hashing
+        state = constants.permute(composer, &state, &values);
+        values = zelbet_out_of_circuit(values);
     });
 
     // Initialise output vector
@@ -106,53 +208,69 @@ pub fn sponge_zelbet_gadget(
 
     if length_out % 2 == 0 {
         (1..length_out / 2).for_each(|k| {
-            state = zelbet_gadget(
-                composer,
-                &state,
-                s_i_decomposition,
-                constants_for_rounds,
-                zero,
-                one,
-                two,
-            );
+            state = constants.permute(composer, &state, &values);
+            values = zelbet_out_of_circuit(values);
             output[2 * k] = state[0];
             output[2 * k + 1] = state[1];
         })
     } else if length_out % 2 == 1 && length_out > 1 {
         (1..(length_out - 1) / 2).for_each(|k| {
-            state = zelbet_gadget(
-                composer,
-                &state,
-                s_i_decomposition,
-                constants_for_rounds,
-                zero,
-                one,
-                two,
-            );
+            state = constants.permute(composer, &state, &values);
+            values = zelbet_out_of_circuit(values);
             output[2 * k] = state[0];
             output[2 * k + 1] = state[1];
         });
-        state = zelbet_gadget(
-            composer,
-            &state,
-            s_i_decomposition,
-            constants_for_rounds,
-            zero,
-            one,
-            two,
-        );
+        state = constants.permute(composer, &state, &values);
         output[length_out - 1] = state[0];
     }
 
     output
 }
 
+/// Add a block of two inputs, given along with their values, to the rate of
+/// the sponge state
+pub(crate) fn absorb_gadget(
+    composer: &mut StandardComposer,
+    state: &mut [Variable; 3],
+    values: &mut [BlsScalar; 3],
+    block: [Variable; 2],
+    block_values: [BlsScalar; 2],
+) {
+    // Field addition of the two scalars together, with the sums allocated
+    // from their values
+    (0..2).for_each(|i| {
+        values[i] += block_values[i];
+        let sum = composer.add_input(values[i]);
+        state[i] = composer.add_gate(
+            state[i],
+            block[i],
+            sum,
+            BlsScalar::one(),
+            BlsScalar::one(),
+            -BlsScalar::one(),
+            BlsScalar::zero(),
+            None,
+        );
+    });
+}
+
 /// Sponge design for out of circuit reinforced concrete, with arbitrary length
 /// input and output. Input length is read by the function, and output length is
 /// an input parameter.
 pub fn sponge_zelbet_out_of(
     input: Vec<BlsScalar>,
     length_out: usize,
+) -> Vec<BlsScalar> {
+    sponge_zelbet_domain_out_of(BlsScalar::one(), input, length_out)
+}
+
+/// Out of circuit sponge with the capacity element initialised to the given
+/// domain, rather than to one, so that hashes computed for different purposes
+/// can never collide
+pub fn sponge_zelbet_domain_out_of(
+    domain: BlsScalar,
+    input: Vec<BlsScalar>,
+    length_out: usize,
 ) -> Vec<BlsScalar> {
     // Initialise input as mutable
     let mut input = input.clone();
@@ -165,8 +283,9 @@ pub fn sponge_zelbet_out_of(
         length_in = length_in + 1;
     }
 
-    // Fixed starting constant values as defined in reinforced concrete paper
-    let mut state = [input[0], input[1], BlsScalar::one()];
+    // Starting values as defined in reinforced concrete paper, with the
+    // capacity set to the domain
+    let mut state = [input[0], input[1], domain];
 
     state = zelbet_out_of_circuit(state);
 
@@ -207,8 +326,8 @@ pub fn sponge_zelbet_out_of(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::gadget_tester;
-    use dusk_plonk::plookup::PlookupTable4Arity;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+    use crate::{gadget_tester, tamper_tester};
     use test::Bencher;
 
     #[bench]
@@ -224,7 +343,7 @@ mod tests {
     #[bench]
     fn bench_sponge_in(b: &mut Bencher) {
         let mut composer = StandardComposer::new();
-        let hash_table = PlookupTable4Arity::create_hash_table();
+        let hash_table = bar_lookup_table();
         composer.append_lookup_table(&hash_table);
         let one = composer.add_input(BlsScalar::one());
         let minus_one = composer.add_input(-BlsScalar::one());
@@ -232,9 +351,21 @@ mod tests {
         let in4 = composer.add_input(BlsScalar::from(298375439085));
         let in5 = composer.add_input(-BlsScalar::from(45));
         let input = vec![one, minus_one, in3, in4, in5];
+        let input_values = [
+            BlsScalar::one(),
+            -BlsScalar::one(),
+            BlsScalar::from(23848872923),
+            BlsScalar::from(298375439085),
+            -BlsScalar::from(45),
+        ];
 
         b.iter(|| {
-            sponge_zelbet_gadget(&mut composer, input.clone(), 5);
+            sponge_zelbet_gadget(
+                &mut composer,
+                input.clone(),
+                &input_values,
+                5,
+            );
         });
     }
 
@@ -257,7 +388,7 @@ mod tests {
                 // (0..5).for_each(|k| {
                 //     println!("BlsScalar({:?}),", out_result[k].0);
                 // });
-                let hash_table = PlookupTable4Arity::create_hash_table();
+                let hash_table = bar_lookup_table();
                 composer.append_lookup_table(&hash_table);
                 let one = composer.add_input(BlsScalar::one());
                 let minus_one = composer.add_input(-BlsScalar::one());
@@ -265,8 +396,16 @@ mod tests {
                 let in4 = composer.add_input(BlsScalar::from(298375439085));
                 let in5 = composer.add_input(-BlsScalar::from(45));
                 let input = vec![one, minus_one, in3, in4, in5];
+                let input_values = [
+                    BlsScalar::one(),
+                    -BlsScalar::one(),
+                    BlsScalar::from(23848872923),
+                    BlsScalar::from(298375439085),
+                    -BlsScalar::from(45),
+                ];
                 println!("circuit size: {:?}", composer.circuit_size());
-                let result = sponge_zelbet_gadget(composer, input, 5);
+                let result =
+                    sponge_zelbet_gadget(composer, input, &input_values, 5);
                 println!("circuit size: {:?}", composer.circuit_size());
 
                 // Compare output values to output from out of circuit version
@@ -339,6 +478,12 @@ mod tests {
                 let result2 = sponge_zelbet_gadget(
                     composer,
                     vec![two, minus_one, in3, in4],
+                    &[
+                        BlsScalar::from(2),
+                        -BlsScalar::one(),
+                        BlsScalar::from(23848872923),
+                        BlsScalar::from(298375439085),
+                    ],
                     4,
                 );
                 println!("circuit size: {:?}", composer.circuit_size());
@@ -386,7 +531,7 @@ mod tests {
                     None,
                 );
             },
-            5000,
+            9000,
         );
         assert!(res.is_ok());
     }
@@ -410,4 +555,96 @@ mod tests {
             println!("second value {} is {:?}", k + 1, output2[k].0);
         });
     }
+
+    #[test]
+    fn test_sponge_domain_separation() {
+        let input = vec![BlsScalar::from(3), BlsScalar::from(5)];
+        assert_eq!(
+            sponge_zelbet_domain_out_of(BlsScalar::one(), input.clone(), 2),
+            sponge_zelbet_out_of(input.clone(), 2)
+        );
+        assert_ne!(
+            sponge_zelbet_domain_out_of(
+                domain_tag(b"rc.first"),
+                input.clone(),
+                2
+            ),
+            sponge_zelbet_domain_out_of(domain_tag(b"rc.second"), input, 2)
+        );
+        assert_ne!(domain_tag(b"rc.first"), BlsScalar::one());
+
+        // Labels that read as small integers, or that only differ by
+        // trailing zero bytes, still give distinct tags
+        assert_ne!(domain_tag(b"\x01\x00"), BlsScalar::one());
+        assert_ne!(domain_tag(b"\x01"), BlsScalar::one());
+        assert_ne!(domain_tag(b"rc"), domain_tag(b"rc\x00"));
+        assert_ne!(domain_tag(b"rc\x00"), domain_tag(b"rc\x00\x00"));
+    }
+
+    #[test]
+    fn test_is_domain_tag() {
+        [&b"rc.mac"[..], b"\x01", b"rc\x00", &[0xff; 31]]
+            .iter()
+            .for_each(|label| assert!(is_domain_tag(&domain_tag(label))));
+        [
+            BlsScalar::zero(),
+            BlsScalar::one(),
+            BlsScalar::from(9),
+            -BlsScalar::one(),
+            domain_tag(b"rc") + BlsScalar::from(1 << 16),
+        ]
+        .iter()
+        .for_each(|scalar| assert!(!is_domain_tag(scalar)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_domain_label() {
+        domain_tag(b"");
+    }
+
+    // Four inputs, so that a second block is absorbed, without constraints on
+    // the output. The values given to the gadget may differ from those of the
+    // input variables.
+    fn sponge_circuit_with(
+        composer: &mut StandardComposer,
+        input_values: &[BlsScalar],
+    ) {
+        let hash_table = bar_lookup_table();
+        composer.append_lookup_table(&hash_table);
+        let input = (1..5u64)
+            .map(|x| composer.add_input(BlsScalar::from(x)))
+            .collect();
+        sponge_zelbet_gadget(composer, input, input_values, 1);
+
+        pad_lookup_gates(composer);
+    }
+
+    fn sponge_circuit(composer: &mut StandardComposer) {
+        let input_values: Vec<BlsScalar> =
+            (1..5u64).map(BlsScalar::from).collect();
+        sponge_circuit_with(composer, &input_values);
+    }
+
+    #[test]
+    fn test_sponge_tampered_witness_fails() {
+        assert!(gadget_tester(sponge_circuit, 5000).is_ok());
+
+        // Values other than those of the inputs make the prover allocate the
+        // outputs of the first concrete layer, for the first block, or the
+        // absorbed state, for the second block, from the wrong values
+        (0..4).for_each(|i| {
+            let prover = |composer: &mut StandardComposer| {
+                let mut input_values: Vec<BlsScalar> =
+                    (1..5u64).map(BlsScalar::from).collect();
+                input_values[i] += BlsScalar::one();
+                sponge_circuit_with(composer, &input_values);
+            };
+            assert!(
+                tamper_tester(&prover, &sponge_circuit, 5000).is_err(),
+                "the value of input {} was not checked",
+                i
+            );
+        });
+    }
 }
@@ -12,8 +12,11 @@ mod brick;
 mod concrete;
 
 use super::gadgets::*;
-use crate::constants::{CONSTANTS_BLS, MONTGOMERY_FOUR, MONTGOMERY_THREE};
+use crate::constants::{
+    CONSTANTS_BLS, MONTGOMERY_FOUR, MONTGOMERY_THREE, MONTGOMERY_TWO,
+};
 pub use bar::bar;
+pub(crate) use bar::decomposition;
 pub use brick::brick;
 pub use concrete::concrete;
 use dusk_plonk::{
@@ -21,72 +24,178 @@ use dusk_plonk::{
     prelude::BlsScalar,
 };
 
-/// In circuit Zelbet hash
+/// Circuit variables for the constants used by every call of [`zelbet_gadget`],
+/// so that they only need to be added to a circuit once
+#[derive(Debug, Clone, Copy)]
+pub struct ZelbetConstants {
+    /// The round constants of the concrete function
+    pub constants_for_rounds: [Variable; 18],
+    pub zero: Variable,
+    pub one: Variable,
+    pub two: Variable,
+}
+
+impl ZelbetConstants {
+    /// Add the constants needed by the Zelbet gadget to the circuit
+    /// description
+    pub fn new(composer: &mut StandardComposer) -> Self {
+        let zero =
+            composer.add_witness_to_circuit_description(BlsScalar::zero());
+        let one = composer.add_witness_to_circuit_description(BlsScalar::one());
+        let two = composer.add_witness_to_circuit_description(MONTGOMERY_TWO);
+
+        let mut constants_for_rounds = [one; 18];
+        (0..6).for_each(|k| {
+            (0..3).for_each(|j| {
+                constants_for_rounds[3 * k + j] = composer
+                    .add_witness_to_circuit_description(CONSTANTS_BLS[k][j]);
+            })
+        });
+
+        ZelbetConstants {
+            constants_for_rounds,
+            zero,
+            one,
+            two,
+        }
+    }
+
+    /// Apply the in circuit Zelbet permutation to the given state, whose
+    /// values are needed to decompose the inputs of the bar function
+    pub fn permute(
+        &self,
+        composer: &mut StandardComposer,
+        state: &[Variable; 3],
+        state_values: &[BlsScalar; 3],
+    ) -> [Variable; 3] {
+        zelbet_gadget(
+            composer,
+            state,
+            state_values,
+            self.constants_for_rounds,
+            self.one,
+            self.two,
+        )
+    }
+}
+
+/// In circuit Zelbet hash, on a state given as variables along with their
+/// values. The values are followed natively through the rounds, as every
+/// layer allocates its outputs from them.
 pub fn zelbet_gadget(
     composer: &mut StandardComposer,
     state: &[Variable; 3],
-    s_i_decomposition: [Variable; 27],
+    state_values: &[BlsScalar; 3],
     constants_for_rounds: [Variable; 18],
-    zero: Variable,
     one: Variable,
     two: Variable,
 ) -> [Variable; 3] {
     // Round 1
     let mut round1_constants = [one; 3];
     round1_constants.copy_from_slice(&constants_for_rounds[0..3]);
-    let mut item = concrete_gadget(composer, state, &round1_constants, 0);
-    item =
-        brick_gadget(composer, &item, two, MONTGOMERY_THREE, MONTGOMERY_FOUR);
+    let mut item =
+        concrete_gadget(composer, state, state_values, &round1_constants, 0);
+    let mut values = concrete(*state_values, CONSTANTS_BLS[0]);
+    item = brick_gadget(
+        composer,
+        &item,
+        &values,
+        two,
+        MONTGOMERY_THREE,
+        MONTGOMERY_FOUR,
+    );
+    values = brick(values);
 
     // Round 2
     let mut round2_constants = [one; 3];
     round2_constants.copy_from_slice(&constants_for_rounds[3..6]);
-    item = concrete_gadget(composer, &item, &round2_constants, 1);
-    item =
-        brick_gadget(composer, &item, two, MONTGOMERY_THREE, MONTGOMERY_FOUR);
+    item = concrete_gadget(composer, &item, &values, &round2_constants, 1);
+    values = concrete(values, CONSTANTS_BLS[1]);
+    item = brick_gadget(
+        composer,
+        &item,
+        &values,
+        two,
+        MONTGOMERY_THREE,
+        MONTGOMERY_FOUR,
+    );
+    values = brick(values);
 
     // Round 3
     let mut round3_constants = [one; 3];
     round3_constants.copy_from_slice(&constants_for_rounds[6..9]);
-    item = concrete_gadget(composer, &item, &round3_constants, 2);
-    item =
-        brick_gadget(composer, &item, two, MONTGOMERY_THREE, MONTGOMERY_FOUR);
+    item = concrete_gadget(composer, &item, &values, &round3_constants, 2);
+    values = concrete(values, CONSTANTS_BLS[2]);
+    item = brick_gadget(
+        composer,
+        &item,
+        &values,
+        two,
+        MONTGOMERY_THREE,
+        MONTGOMERY_FOUR,
+    );
+    values = brick(values);
 
     // Round 4
     let mut round4_constants = [one; 3];
     round4_constants.copy_from_slice(&constants_for_rounds[9..12]);
-    item = concrete_gadget(composer, &item, &round4_constants, 3);
+    item = concrete_gadget(composer, &item, &values, &round4_constants, 3);
+    values = concrete(values, CONSTANTS_BLS[3]);
     // Apply bar function to each entry
     (0..3).for_each(|k| {
-        item[k] =
-            bar_gadget(composer, item[k], s_i_decomposition, zero, one, two);
+        item[k] = bar_gadget(composer, item[k], values[k]);
     });
+    bar(&mut values);
 
     // Round 5
     let mut round5_constants = [one; 3];
     round5_constants.copy_from_slice(&constants_for_rounds[12..15]);
-    item = concrete_gadget(composer, &item, &round5_constants, 4);
-    item =
-        brick_gadget(composer, &item, two, MONTGOMERY_THREE, MONTGOMERY_FOUR);
+    item = concrete_gadget(composer, &item, &values, &round5_constants, 4);
+    values = concrete(values, CONSTANTS_BLS[4]);
+    item = brick_gadget(
+        composer,
+        &item,
+        &values,
+        two,
+        MONTGOMERY_THREE,
+        MONTGOMERY_FOUR,
+    );
+    values = brick(values);
 
     // Round 6
     let mut round6_constants = [one; 3];
     round6_constants.copy_from_slice(&constants_for_rounds[15..18]);
-    item = concrete_gadget(composer, &item, &round6_constants, 5);
-    item =
-        brick_gadget(composer, &item, two, MONTGOMERY_THREE, MONTGOMERY_FOUR);
+    item = concrete_gadget(composer, &item, &values, &round6_constants, 5);
+    values = concrete(values, CONSTANTS_BLS[5]);
+    item = brick_gadget(
+        composer,
+        &item,
+        &values,
+        two,
+        MONTGOMERY_THREE,
+        MONTGOMERY_FOUR,
+    );
+    values = brick(values);
 
     // Round 7
     let mut round7_constants = [one; 3];
     round7_constants.copy_from_slice(&constants_for_rounds[12..15]);
-    item = concrete_gadget(composer, &item, &round7_constants, 4);
-    item =
-        brick_gadget(composer, &item, two, MONTGOMERY_THREE, MONTGOMERY_FOUR);
+    item = concrete_gadget(composer, &item, &values, &round7_constants, 4);
+    values = concrete(values, CONSTANTS_BLS[4]);
+    item = brick_gadget(
+        composer,
+        &item,
+        &values,
+        two,
+        MONTGOMERY_THREE,
+        MONTGOMERY_FOUR,
+    );
+    values = brick(values);
 
     // Final concrete
     let mut round8_constants = [one; 3];
     round8_constants.copy_from_slice(&constants_for_rounds[9..12]);
-    item = concrete_gadget(composer, &item, &round8_constants, 3);
+    item = concrete_gadget(composer, &item, &values, &round8_constants, 3);
 
     item
 }
@@ -116,8 +225,7 @@ pub fn zelbet_out_of_circuit(scalar_inputs: [BlsScalar; 3]) -> [BlsScalar; 3] {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{constants::S_I_DECOMPOSITION_MONTGOMERY, gadget_tester};
-    use dusk_plonk::plookup::PlookupTable4Arity;
+    use crate::gadget_tester;
     use test::Bencher;
 
     #[bench]
@@ -130,17 +238,10 @@ mod tests {
     #[bench]
     fn bench_zelbet_in(b: &mut Bencher) {
         let mut composer = StandardComposer::new();
-        let hash_table = PlookupTable4Arity::create_hash_table();
+        let hash_table = bar_lookup_table();
         composer.append_lookup_table(&hash_table);
-        let zero = composer.add_input(BlsScalar::zero());
         let one = composer.add_input(BlsScalar::one());
         let two = composer.add_input(BlsScalar::from(2));
-        let mut s_i_decomposition = [one; 27];
-        (0..27).for_each(|k| {
-            s_i_decomposition[k] = composer.add_witness_to_circuit_description(
-                S_I_DECOMPOSITION_MONTGOMERY[k],
-            );
-        });
         let mut constants_for_rounds = [one; 18];
         (0..6).for_each(|k| {
             (0..3).for_each(|j| {
@@ -152,9 +253,8 @@ mod tests {
             zelbet_gadget(
                 &mut composer,
                 &[one; 3],
-                s_i_decomposition,
+                &[BlsScalar::one(); 3],
                 constants_for_rounds,
-                zero,
                 one,
                 two,
             )
@@ -165,19 +265,11 @@ mod tests {
     fn test_zelbet_in_gadget_circuit() {
         let res = gadget_tester(
             |composer| {
-                let hash_table = PlookupTable4Arity::create_hash_table();
+                let hash_table = bar_lookup_table();
                 composer.append_lookup_table(&hash_table);
-                let zero = composer.add_input(BlsScalar::zero());
                 let one = composer.add_input(BlsScalar::one());
                 let two = composer.add_input(BlsScalar::from(2));
                 println!("circuit size: {:?}", composer.circuit_size());
-                let mut s_i_decomposition = [one; 27];
-                (0..27).for_each(|k| {
-                    s_i_decomposition[k] = composer
-                        .add_witness_to_circuit_description(
-                            S_I_DECOMPOSITION_MONTGOMERY[k],
-                        );
-                });
                 let mut constants_for_rounds = [one; 18];
                 (0..6).for_each(|k| {
                     (0..3).for_each(|j| {
@@ -191,37 +283,14 @@ mod tests {
                 let _result = zelbet_gadget(
                     composer,
                     &[one; 3],
-                    s_i_decomposition,
+                    &[BlsScalar::one(); 3],
                     constants_for_rounds,
-                    zero,
                     one,
                     two,
                 );
                 println!("circuit size: {:?}", composer.circuit_size());
 
-                let one_eight_seven = composer.add_input(BlsScalar::from(187));
-                let zero = composer.add_input(BlsScalar::from(0));
-
-                // Plookup is designed to not pass if the number of plookup
-                // checks is much smaller than the size of the lookup table, so
-                // these extra checks are to increase the number of plookup
-                // gates
-                (0..500).for_each(|_| {
-                    composer.plookup_gate(
-                        one,
-                        zero,
-                        one_eight_seven,
-                        Some(one),
-                        BlsScalar::zero(),
-                    );
-                    composer.plookup_gate(
-                        one,
-                        one,
-                        one,
-                        Some(one),
-                        BlsScalar::zero(),
-                    );
-                });
+                pad_lookup_gates(composer);
             },
             3000,
         );
@@ -41,44 +41,53 @@ fn small_s_box(x: u256) -> u256 {
     }
 }
 
+/// Decomposition of a scalar in the radices `s_k` of the bar function, from
+/// the least significant digit to the most significant one (out of circuit)
+pub(crate) fn decomposition(scalar: &Scalar) -> [u64; DECOMPOSITION_LEN] {
+    let mut digits = [0u64; DECOMPOSITION_LEN];
+
+    // Get the value that we are decomposing in non-Montgomery form (comes in
+    // Montgomery form by default due to BLS library; but the modular
+    // operations can't be done if left like this)
+    let mut intermediate = scalar.reduce().0;
+    // s should be set to the number of leading zeros of div in each iteration
+    // of the loop below, but under BLS conditions this value is always 54
+    let s: u32 = 54;
+
+    (0..DECOMPOSITION_LEN).for_each(|k| {
+        digits[k] = match k < DECOMPOSITION_LEN - 1 {
+            true => {
+                // precomputation for modular operation
+                let divisor = BLS_DIVISORS[k];
+                let recip = BLS_RECIP[k];
+                // division: intermediate = u0*divisor + u1
+                let (u0, u1) = divide_w_recip::divide_long_using_recip(
+                    &intermediate,
+                    divisor,
+                    recip,
+                    s,
+                );
+                intermediate = u0;
+                u1 as u64
+            }
+            false => intermediate[0],
+        };
+    });
+
+    digits
+}
+
 /// Bar function (out of circuit)
 pub fn bar(state: &mut [Scalar; 3]) {
     let mut nibbles = [u256::zero(); 27];
 
     for scalar in state.iter_mut() {
-        // println!("input is {:?}", scalar.reduce().0);
         // 1. Decomposition
-        // Get state value that we are decomposing in non-Montgomery form (comes
-        // in Montgomery form by default due to BLS library; but the
-        // modular operations can't be done if left like this)
-        let mut intermediate = scalar.reduce().0;
-        let mut remainder = 0u16;
-        // s should be set to the number of leading zeros of div in each
-        // iteration of the loop below, but under BLS conditions this value is
-        // always 54
-        let s: u32 = 54;
+        let digits = decomposition(scalar);
 
+        // 2. S-box
         (0..27).for_each(|k| {
-            // Reduce intermediate representation
-            match k < 26 {
-                true => {
-                    // precomputation for modular operation
-                    let divisor = BLS_DIVISORS[k];
-                    let recip = BLS_RECIP[k];
-                    // division: intermediate = u0*divisor + u1
-                    let (u0, u1) = divide_w_recip::divide_long_using_recip(
-                        &intermediate,
-                        divisor,
-                        recip,
-                        s,
-                    );
-                    intermediate = u0;
-                    remainder = u1;
-                }
-                false => remainder = intermediate[0] as u16,
-            };
-            // 2. S-box
-            nibbles[k] = small_s_box(u256([remainder as u64, 0, 0, 0]));
+            nibbles[k] = small_s_box(u256([digits[k], 0, 0, 0]));
         });
 
         // 3. Composition
@@ -215,6 +224,16 @@ mod tests {
         assert_eq!(input[0], composed);
     }
 
+    #[test]
+    fn test_decomposition() {
+        let digits = decomposition(&-Scalar::one());
+        (0..27).for_each(|k| assert_eq!(digits[k], BLS_SCALAR_REAL[k].0[0]));
+
+        let mut expected = [0; 27];
+        expected[0] = 1;
+        assert_eq!(decomposition(&Scalar::one()), expected);
+    }
+
     #[test]
     fn test_inverses() {
         for k in 0..27 {
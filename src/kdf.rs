@@ -0,0 +1,243 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Key derivation with the extract-then-expand structure of HKDF, over the
+//! domain separated Zelbet sponge.
+//!
+//! Extraction absorbs the [`length_prefixed_input`] of the salt and the input
+//! key material, and squeezes a single pseudorandom key. Expansion absorbs
+//! `[prk, info, n]`, with the info string encoded by [`bytes_to_scalars`], and
+//! squeezes `n` keys. The two steps use different domains, so that an
+//! extracted key can never be mistaken for an expanded one.
+
+use crate::hashing::sponge::{
+    bytes_to_scalars, domain_tag, length_prefixed_input,
+    length_prefixed_input_gadget, sponge_zelbet_domain_gadget,
+    sponge_zelbet_domain_out_of,
+};
+use crate::hashing::zelbet::ZelbetConstants;
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Label of the sponge domain used for extraction
+pub const EXTRACT_DOMAIN: &[u8] = b"rc.kdf.extract";
+
+/// Label of the sponge domain used for expansion
+pub const EXPAND_DOMAIN: &[u8] = b"rc.kdf.expand";
+
+/// Extract a pseudorandom key from the input key material (out of circuit).
+/// The salt should be zero when there is none.
+pub fn extract(salt: BlsScalar, ikm: &[BlsScalar]) -> BlsScalar {
+    let input = length_prefixed_input(salt, ikm);
+    sponge_zelbet_domain_out_of(domain_tag(EXTRACT_DOMAIN), input, 1)[0]
+}
+
+/// Expand a pseudorandom key into `n` keys bound to the info string (out of
+/// circuit)
+pub fn expand(prk: BlsScalar, info: &[u8], n: usize) -> Vec<BlsScalar> {
+    let mut input = vec![prk];
+    input.extend(bytes_to_scalars(info));
+    input.push(BlsScalar::from(n as u64));
+    sponge_zelbet_domain_out_of(domain_tag(EXPAND_DOMAIN), input, n)
+}
+
+/// Extract in circuit, where the salt and the input key material are given as
+/// variables along with their values.
+pub fn extract_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    salt: Variable,
+    salt_value: BlsScalar,
+    ikm: &[Variable],
+    ikm_values: &[BlsScalar],
+) -> Variable {
+    let (input, input_values) = length_prefixed_input_gadget(
+        composer, salt, salt_value, ikm, ikm_values,
+    );
+    sponge_zelbet_domain_gadget(
+        composer,
+        constants,
+        domain_tag(EXTRACT_DOMAIN),
+        input,
+        &input_values,
+        1,
+    )[0]
+}
+
+/// Expand in circuit, where the pseudorandom key is given as a variable along
+/// with its value. The info string and number of keys are part of the circuit
+/// description.
+pub fn expand_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    prk: Variable,
+    prk_value: BlsScalar,
+    info: &[u8],
+    n: usize,
+) -> Vec<Variable> {
+    let mut input_values = vec![prk_value];
+    input_values.extend(bytes_to_scalars(info));
+    input_values.push(BlsScalar::from(n as u64));
+    let mut input = vec![prk];
+    input_values[1..].iter().for_each(|element| {
+        input.push(composer.add_witness_to_circuit_description(*element))
+    });
+    sponge_zelbet_domain_gadget(
+        composer,
+        constants,
+        domain_tag(EXPAND_DOMAIN),
+        input,
+        &input_values,
+        n,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+
+    #[test]
+    fn test_kdf_vectors() {
+        let prk = extract(BlsScalar::zero(), &[BlsScalar::from(5)]);
+        assert_eq!(
+            prk,
+            BlsScalar([
+                13071969998009287232,
+                8868033491263320160,
+                8322764492901041130,
+                6344270049661908321,
+            ])
+        );
+        assert_eq!(
+            expand(prk, b"viewing key", 2),
+            vec![
+                BlsScalar([
+                    6369659506748544940,
+                    7319418623077090006,
+                    156460933255025069,
+                    7669770366770995605,
+                ]),
+                BlsScalar([
+                    2624661882768172115,
+                    513335035864975188,
+                    12729795026010599835,
+                    5249758013767873969,
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kdf_separation() {
+        let ikm = [BlsScalar::from(5)];
+        let prk = extract(BlsScalar::zero(), &ikm);
+        assert_ne!(prk, extract(BlsScalar::one(), &ikm));
+        assert_ne!(prk, extract(BlsScalar::zero(), &[BlsScalar::from(6)]));
+        assert_ne!(
+            prk,
+            extract(BlsScalar::zero(), &[ikm[0], BlsScalar::one()])
+        );
+
+        let keys = expand(prk, b"viewing key", 2);
+        assert_ne!(keys, expand(prk, b"spending key", 2));
+        assert_ne!(keys[0], expand(prk, b"viewing key", 1)[0]);
+        assert_ne!(keys[0], prk);
+        assert_ne!(keys[0], keys[1]);
+    }
+
+    #[test]
+    fn test_kdf_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                // Derive a viewing key from a spending key
+                let spending_key = composer.add_input(BlsScalar::from(5));
+                let prk = extract_gadget(
+                    composer,
+                    &constants,
+                    constants.zero,
+                    BlsScalar::zero(),
+                    &[spending_key],
+                    &[BlsScalar::from(5)],
+                );
+                let viewing_key = expand_gadget(
+                    composer,
+                    &constants,
+                    prk,
+                    extract(BlsScalar::zero(), &[BlsScalar::from(5)]),
+                    b"viewing key",
+                    1,
+                );
+
+                let expected = expand(
+                    extract(BlsScalar::zero(), &[BlsScalar::from(5)]),
+                    b"viewing key",
+                    1,
+                );
+                composer.constrain_to_constant(
+                    viewing_key[0],
+                    BlsScalar::zero(),
+                    Some(-expected[0]),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            9000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_kdf_gadget_wrong_key_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let spending_key = composer.add_input(BlsScalar::from(6));
+                let prk = extract_gadget(
+                    composer,
+                    &constants,
+                    constants.zero,
+                    BlsScalar::zero(),
+                    &[spending_key],
+                    &[BlsScalar::from(6)],
+                );
+                let viewing_key = expand_gadget(
+                    composer,
+                    &constants,
+                    prk,
+                    extract(BlsScalar::zero(), &[BlsScalar::from(6)]),
+                    b"viewing key",
+                    1,
+                );
+
+                let expected = expand(
+                    extract(BlsScalar::zero(), &[BlsScalar::from(5)]),
+                    b"viewing key",
+                    1,
+                );
+                composer.constrain_to_constant(
+                    viewing_key[0],
+                    BlsScalar::zero(),
+                    Some(-expected[0]),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            9000,
+        );
+        assert!(res.is_err());
+    }
+}
@@ -8,8 +8,24 @@
 
 extern crate test;
 
+pub mod aead;
+pub mod commitment;
 pub mod constants;
+pub mod hash_chain;
+pub mod hash_to_curve;
 pub mod hashing;
+pub mod kdf;
+pub mod mac;
+pub mod merkle;
+pub mod preimage;
+pub mod prf;
+pub mod schnorr;
 mod test_helper;
+pub mod transcript;
+pub mod vrf;
+pub mod wots;
+pub mod xmss;
 
 pub(crate) use test_helper::gadget_tester;
+#[cfg(test)]
+pub(crate) use test_helper::tamper_tester;
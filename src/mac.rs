@@ -0,0 +1,289 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Message authentication codes over vectors of BLS scalars, computed with the
+//! Zelbet sponge keyed through its capacity element. The key takes the place
+//! of the one that the plain sponge puts in the capacity, and the input to the
+//! sponge is the [`length_prefixed_input`] of the tag of [`MAC_DOMAIN`] and
+//! the message, so that tags are separated from the outputs of other keyed
+//! constructions.
+//!
+//! A key in the capacity can collide with a public capacity. A key of one
+//! gives the plain sponge, and a key equal to a [`domain_tag`] gives a domain
+//! separated sponge, so the tags under such a key can be computed by anyone.
+//! These weak keys, see [`is_weak_mac_key`], are rejected.
+
+use crate::hashing::sponge::{
+    domain_tag, is_domain_tag, length_prefixed_input,
+    length_prefixed_input_gadget, sponge_zelbet_domain_out_of,
+    sponge_zelbet_keyed_gadget,
+};
+use crate::hashing::zelbet::ZelbetConstants;
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+use subtle::ConstantTimeEq;
+
+/// Label of the domain absorbed first by the MAC
+pub const MAC_DOMAIN: &[u8] = b"rc.mac";
+
+/// Whether the key equals a public capacity of the sponge, which is one or a
+/// [`domain_tag`]
+pub fn is_weak_mac_key(key: &BlsScalar) -> bool {
+    *key == BlsScalar::one() || is_domain_tag(key)
+}
+
+/// Compute the tag of a message under the key (out of circuit), or `None`
+/// for a weak key
+pub fn mac(key: BlsScalar, message: &[BlsScalar]) -> Option<BlsScalar> {
+    if is_weak_mac_key(&key) {
+        return None;
+    }
+    let input = length_prefixed_input(domain_tag(MAC_DOMAIN), message);
+    Some(sponge_zelbet_domain_out_of(key, input, 1)[0])
+}
+
+/// Check the tag of a message under the key (out of circuit). The tags are
+/// compared in constant time, and no tag is valid under a weak key.
+pub fn verify_mac(
+    key: BlsScalar,
+    message: &[BlsScalar],
+    tag: BlsScalar,
+) -> bool {
+    match mac(key, message) {
+        Some(expected) => expected.ct_eq(&tag).into(),
+        None => false,
+    }
+}
+
+/// Compute the tag of a message under the key in circuit, where the key and
+/// the message are given as variables along with their values.
+///
+/// A weak key is rejected when the circuit is built from its value. The key
+/// is not constrained against weak keys, which only protects keys that are
+/// chosen honestly.
+pub fn mac_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    key: Variable,
+    key_value: BlsScalar,
+    message: &[Variable],
+    message_values: &[BlsScalar],
+) -> Variable {
+    assert!(!is_weak_mac_key(&key_value), "the MAC key is weak");
+    let domain_value = domain_tag(MAC_DOMAIN);
+    let domain = composer.add_witness_to_circuit_description(domain_value);
+    let (input, input_values) = length_prefixed_input_gadget(
+        composer,
+        domain,
+        domain_value,
+        message,
+        message_values,
+    );
+    sponge_zelbet_keyed_gadget(
+        composer,
+        constants,
+        key,
+        key_value,
+        input,
+        &input_values,
+        1,
+    )[0]
+}
+
+/// Verify in circuit that the tag authenticates the message under the key,
+/// where the key and the message are given along with their values
+pub fn mac_verification_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    key: Variable,
+    key_value: BlsScalar,
+    message: &[Variable],
+    message_values: &[BlsScalar],
+    tag: Variable,
+) {
+    let computed = mac_gadget(
+        composer,
+        constants,
+        key,
+        key_value,
+        message,
+        message_values,
+    );
+    composer.assert_equal(computed, tag);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+    use crate::hashing::sponge::sponge_zelbet_out_of;
+    use crate::prf::{prf, PRF_DOMAIN};
+
+    #[test]
+    fn test_mac_vectors() {
+        assert_eq!(
+            mac(
+                BlsScalar::from(9),
+                &[BlsScalar::from(1), BlsScalar::from(2), BlsScalar::from(3)]
+            ),
+            Some(BlsScalar([
+                3205519458072917925,
+                1580634950939730694,
+                12741392354279806668,
+                6345827286669792784,
+            ]))
+        );
+        assert_eq!(
+            mac(-BlsScalar::one(), &[]),
+            Some(BlsScalar([
+                12358152425340887729,
+                12631702378203033914,
+                3425853754496074014,
+                210217622328983574,
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_mac_key_separation() {
+        let message = [BlsScalar::from(1), BlsScalar::from(2)];
+        let tag = mac(BlsScalar::from(9), &message).unwrap();
+        assert!(verify_mac(BlsScalar::from(9), &message, tag));
+        assert!(!verify_mac(BlsScalar::from(10), &message, tag));
+
+        // The key is not absorbed, so the MAC is not the PRF under the same
+        // key
+        let key = BlsScalar::from(9);
+        assert_ne!(tag, prf(key, &message));
+    }
+
+    #[test]
+    fn test_mac_weak_keys() {
+        let message = [BlsScalar::from(1), BlsScalar::from(2)];
+        let public_input = vec![
+            domain_tag(MAC_DOMAIN),
+            BlsScalar::from(2),
+            message[0],
+            message[1],
+        ];
+
+        // A key of one gives the plain sponge, and a key equal to a domain
+        // tag gives a public domain of the sponge
+        let tag = sponge_zelbet_out_of(public_input.clone(), 1)[0];
+        assert!(is_weak_mac_key(&BlsScalar::one()));
+        assert_eq!(mac(BlsScalar::one(), &message), None);
+        assert!(!verify_mac(BlsScalar::one(), &message, tag));
+        [PRF_DOMAIN, MAC_DOMAIN].iter().for_each(|label| {
+            let key = domain_tag(label);
+            let tag = sponge_zelbet_domain_out_of(key, public_input.clone(), 1);
+            assert!(is_weak_mac_key(&key));
+            assert_eq!(mac(key, &message), None);
+            assert!(!verify_mac(key, &message, tag[0]));
+        });
+
+        [BlsScalar::zero(), BlsScalar::from(2), -BlsScalar::one()]
+            .iter()
+            .for_each(|key| assert!(!is_weak_mac_key(key)));
+    }
+
+    #[test]
+    fn test_mac_message_separation() {
+        let key = BlsScalar::from(9);
+        let message = [BlsScalar::from(1), BlsScalar::from(2)];
+        let tag = mac(key, &message).unwrap();
+        assert!(!verify_mac(key, &[message[1], message[0]], tag));
+        assert!(!verify_mac(key, &message[..1], tag));
+        assert!(!verify_mac(
+            key,
+            &[message[0], message[1], BlsScalar::one()],
+            tag
+        ));
+        assert!(!verify_mac(key, &message, tag + BlsScalar::one()));
+        assert_ne!(mac(key, &[]), mac(key, &[BlsScalar::zero()]));
+    }
+
+    #[test]
+    fn test_mac_verification_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let message_scalars = [BlsScalar::from(1), BlsScalar::from(2)];
+                let tag_scalar =
+                    mac(BlsScalar::from(9), &message_scalars).unwrap();
+
+                let key = composer.add_input(BlsScalar::from(9));
+                let message: Vec<Variable> = message_scalars
+                    .iter()
+                    .map(|m| composer.add_input(*m))
+                    .collect();
+                let tag = composer.add_input(tag_scalar);
+                mac_verification_gadget(
+                    composer,
+                    &constants,
+                    key,
+                    BlsScalar::from(9),
+                    &message,
+                    &message_scalars,
+                    tag,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            5000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_mac_verification_gadget_wrong_key_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let message_scalars = [BlsScalar::from(1), BlsScalar::from(2)];
+                let tag_scalar =
+                    mac(BlsScalar::from(9), &message_scalars).unwrap();
+
+                let key = composer.add_input(BlsScalar::from(10));
+                let message: Vec<Variable> = message_scalars
+                    .iter()
+                    .map(|m| composer.add_input(*m))
+                    .collect();
+                let tag = composer.add_input(tag_scalar);
+                mac_verification_gadget(
+                    composer,
+                    &constants,
+                    key,
+                    BlsScalar::from(10),
+                    &message,
+                    &message_scalars,
+                    tag,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            5000,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mac_gadget_weak_key() {
+        let mut composer = StandardComposer::new();
+        let constants = ZelbetConstants::new(&mut composer);
+        let key = composer.add_input(BlsScalar::one());
+        mac_gadget(&mut composer, &constants, key, BlsScalar::one(), &[], &[]);
+    }
+}
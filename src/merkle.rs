@@ -0,0 +1,104 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Merkle tree constructions, using the Zelbet permutation as the two-to-one
+//! compression function for the nodes of the tree
+
+pub mod frontier;
+pub mod opening;
+pub mod sparse;
+
+use crate::hashing::sponge::domain_tag;
+use crate::hashing::zelbet::{zelbet_out_of_circuit, ZelbetConstants};
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Label of the domain placed in the capacity when compressing nodes
+pub const MERKLE_DOMAIN: &[u8] = b"rc.merkle";
+
+/// Compress two nodes of a tree into their parent (out of circuit). The
+/// children are placed in the rate of the Zelbet state, with the capacity set
+/// to the tag of [`MERKLE_DOMAIN`] so that nodes never collide with sponge
+/// hashes, and the first element of the output is kept.
+pub fn merkle_compress(left: BlsScalar, right: BlsScalar) -> BlsScalar {
+    zelbet_out_of_circuit([left, right, domain_tag(MERKLE_DOMAIN)])[0]
+}
+
+/// Hashes of the empty subtrees of every height, from the empty leaf (zero) at
+/// index 0 up to the root of the empty tree at index `depth`
+pub fn empty_subtree_hashes(depth: usize) -> Vec<BlsScalar> {
+    let mut empty = vec![BlsScalar::zero(); depth + 1];
+    (0..depth).for_each(|level| {
+        empty[level + 1] = merkle_compress(empty[level], empty[level]);
+    });
+    empty
+}
+
+/// Add the tag of [`MERKLE_DOMAIN`] to the circuit description, to be shared
+/// by the compressions of a circuit
+pub fn merkle_domain_gadget(composer: &mut StandardComposer) -> Variable {
+    composer.add_witness_to_circuit_description(domain_tag(MERKLE_DOMAIN))
+}
+
+/// Compress two nodes of a tree into their parent (in circuit), where the
+/// domain is given by [`merkle_domain_gadget`] and the nodes are given as
+/// variables along with their values
+pub fn merkle_compress_domain_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    domain: Variable,
+    left: Variable,
+    left_value: BlsScalar,
+    right: Variable,
+    right_value: BlsScalar,
+) -> Variable {
+    constants.permute(
+        composer,
+        &[left, right, domain],
+        &[left_value, right_value, domain_tag(MERKLE_DOMAIN)],
+    )[0]
+}
+
+/// Compress two nodes of a tree into their parent (in circuit), where the
+/// nodes are given as variables along with their values
+pub fn merkle_compress_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    left: Variable,
+    left_value: BlsScalar,
+    right: Variable,
+    right_value: BlsScalar,
+) -> Variable {
+    let domain = merkle_domain_gadget(composer);
+    merkle_compress_domain_gadget(
+        composer,
+        constants,
+        domain,
+        left,
+        left_value,
+        right,
+        right_value,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashing::sponge::sponge_zelbet_out_of;
+
+    #[test]
+    fn test_merkle_compress_separated_from_sponge() {
+        let left = BlsScalar::from(3);
+        let right = -BlsScalar::from(7);
+        assert_ne!(
+            merkle_compress(left, right),
+            sponge_zelbet_out_of(vec![left, right], 1)[0]
+        );
+        assert_ne!(merkle_compress(left, right), merkle_compress(right, left));
+    }
+}
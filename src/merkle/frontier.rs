@@ -0,0 +1,316 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Append-only Merkle tree that only stores its frontier, i.e. the root of the
+//! last complete subtree at every level. Leaves are appended from position
+//! zero onwards, and the empty positions to the right hold zero, so the root
+//! is the same as that of a sparse Merkle tree keyed by position.
+//!
+//! The authentication paths of selected leaves are kept up to date as new
+//! leaves arrive, which only requires work for the single subtree that is
+//! being filled to the right of each tracked leaf.
+
+use super::opening::merkle_root_out_of;
+use super::{empty_subtree_hashes, merkle_compress};
+use dusk_plonk::prelude::BlsScalar;
+use std::collections::BTreeMap;
+
+/// Frontier of a tree of fixed depth. When bit `l` of the size is set,
+/// `nodes[l]` is the root of the complete subtree of height `l` just to the
+/// left of the next free position. `nodes[depth]` holds the root once the tree
+/// is full.
+#[derive(Debug, Clone)]
+struct Frontier {
+    size: u64,
+    nodes: Vec<BlsScalar>,
+}
+
+impl Frontier {
+    fn new(depth: usize) -> Self {
+        Frontier {
+            size: 0,
+            nodes: vec![BlsScalar::zero(); depth + 1],
+        }
+    }
+
+    fn depth(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    fn is_full(&self) -> bool {
+        self.size == 1 << self.depth()
+    }
+
+    /// Append a leaf, hashing together the subtrees that it completes
+    fn append(&mut self, leaf: BlsScalar) {
+        assert!(!self.is_full(), "tree is full");
+        self.size += 1;
+        let size = self.size;
+        let mut node = leaf;
+        for (level, stored) in self.nodes.iter_mut().enumerate() {
+            if (size >> level) & 1 == 1 {
+                *stored = node;
+                return;
+            }
+            node = merkle_compress(*stored, node);
+        }
+    }
+
+    /// Root of the tree, where the free positions are filled using the hashes
+    /// of the empty subtrees
+    fn root(&self, empty: &[BlsScalar]) -> BlsScalar {
+        let depth = self.depth();
+        if self.is_full() {
+            return self.nodes[depth];
+        }
+        (0..depth).fold(empty[0], |node, level| {
+            match (self.size >> level) & 1 == 1 {
+                true => merkle_compress(self.nodes[level], node),
+                false => merkle_compress(node, empty[level]),
+            }
+        })
+    }
+}
+
+/// Authentication path of a leaf in a [`MerkleFrontier`], valid for the root
+/// of the tree at the time the witness was taken
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleWitness {
+    /// Position of the leaf in the tree
+    pub position: u64,
+    /// Value of the leaf
+    pub leaf: BlsScalar,
+    /// Siblings on the path from the leaf up to the root
+    pub siblings: Vec<BlsScalar>,
+}
+
+impl MerkleWitness {
+    /// Path bits of the leaf, where a one means that the node is the right
+    /// child at that level
+    pub fn path(&self) -> Vec<bool> {
+        (0..self.siblings.len())
+            .map(|level| (self.position >> level) & 1 == 1)
+            .collect()
+    }
+
+    /// Root of the tree that the witness opens
+    pub fn root(&self) -> BlsScalar {
+        merkle_root_out_of(self.leaf, &self.siblings, &self.path())
+    }
+}
+
+/// Authentication path of a tracked leaf that is still being completed. The
+/// siblings to the left are known when the leaf is appended, and those to the
+/// right are filled one subtree at a time as new leaves arrive.
+#[derive(Debug, Clone)]
+struct TrackedLeaf {
+    leaf: BlsScalar,
+    siblings: Vec<Option<BlsScalar>>,
+    cursor: Option<Frontier>,
+}
+
+impl TrackedLeaf {
+    /// Record a leaf appended after the tracked one
+    fn observe(&mut self, position: u64, new_position: u64, leaf: BlsScalar) {
+        // The new leaf lies in the sibling subtree at the level of the most
+        // significant bit in which the two positions differ
+        let level = 63 - (position ^ new_position).leading_zeros() as usize;
+        if self.cursor.as_ref().map(|cursor| cursor.depth()) != Some(level) {
+            self.cursor = Some(Frontier::new(level));
+        }
+
+        let cursor = self.cursor.as_mut().expect("cursor is set");
+        cursor.append(leaf);
+        if cursor.is_full() {
+            self.siblings[level] = Some(cursor.nodes[level]);
+            self.cursor = None;
+        }
+    }
+}
+
+/// Append-only Merkle tree storing one node per level, with authentication
+/// paths kept for selected leaves
+#[derive(Debug, Clone)]
+pub struct MerkleFrontier {
+    empty: Vec<BlsScalar>,
+    frontier: Frontier,
+    tracked: BTreeMap<u64, TrackedLeaf>,
+}
+
+impl MerkleFrontier {
+    /// Create an empty tree of the given depth, which holds up to 2^depth
+    /// leaves
+    pub fn new(depth: usize) -> Self {
+        assert!(depth < 64, "positions are stored as u64");
+        MerkleFrontier {
+            empty: empty_subtree_hashes(depth),
+            frontier: Frontier::new(depth),
+            tracked: BTreeMap::new(),
+        }
+    }
+
+    /// Depth of the tree
+    pub fn depth(&self) -> usize {
+        self.frontier.depth()
+    }
+
+    /// Number of leaves appended so far, which is also the position of the
+    /// next leaf
+    pub fn size(&self) -> u64 {
+        self.frontier.size
+    }
+
+    /// Current root of the tree
+    pub fn root(&self) -> BlsScalar {
+        self.frontier.root(&self.empty)
+    }
+
+    /// Append a leaf to the tree, returning its position
+    pub fn append(&mut self, leaf: BlsScalar) -> u64 {
+        let new_position = self.frontier.size;
+        self.tracked.iter_mut().for_each(|(position, tracked)| {
+            tracked.observe(*position, new_position, leaf)
+        });
+        self.frontier.append(leaf);
+
+        new_position
+    }
+
+    /// Append a leaf to the tree and keep its authentication path up to date
+    /// as further leaves are appended
+    pub fn append_tracked(&mut self, leaf: BlsScalar) -> u64 {
+        // Before appending, the frontier holds exactly the left siblings of
+        // the next position
+        let position = self.frontier.size;
+        let siblings = (0..self.depth())
+            .map(|level| match (position >> level) & 1 == 1 {
+                true => Some(self.frontier.nodes[level]),
+                false => None,
+            })
+            .collect();
+
+        self.append(leaf);
+        self.tracked.insert(
+            position,
+            TrackedLeaf {
+                leaf,
+                siblings,
+                cursor: None,
+            },
+        );
+
+        position
+    }
+
+    /// Stop updating the authentication path of a tracked leaf
+    pub fn untrack(&mut self, position: u64) {
+        self.tracked.remove(&position);
+    }
+
+    /// Positions of the leaves being tracked
+    pub fn tracked_positions(&self) -> Vec<u64> {
+        self.tracked.keys().copied().collect()
+    }
+
+    /// Authentication path of a tracked leaf against the current root
+    pub fn witness(&self, position: u64) -> Option<MerkleWitness> {
+        self.tracked.get(&position).map(|tracked| {
+            let siblings = tracked
+                .siblings
+                .iter()
+                .enumerate()
+                .map(|(level, sibling)| match sibling {
+                    Some(sibling) => *sibling,
+                    None => match &tracked.cursor {
+                        Some(cursor) if cursor.depth() == level => {
+                            cursor.root(&self.empty)
+                        }
+                        _ => self.empty[level],
+                    },
+                })
+                .collect();
+
+            MerkleWitness {
+                position,
+                leaf: tracked.leaf,
+                siblings,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle::sparse::SparseMerkleTree;
+
+    fn leaf(position: u64) -> BlsScalar {
+        BlsScalar::from(1000 + position)
+    }
+
+    #[test]
+    fn test_frontier_matches_sparse_tree() {
+        let depth = 5;
+        let mut frontier = MerkleFrontier::new(depth);
+        let mut sparse = SparseMerkleTree::with_depth(depth);
+        assert_eq!(frontier.root(), sparse.root());
+
+        (0..11).for_each(|position| {
+            assert_eq!(frontier.append(leaf(position)), position);
+            sparse.insert(BlsScalar::from(position), leaf(position));
+            assert_eq!(frontier.root(), sparse.root());
+        });
+    }
+
+    #[test]
+    fn test_full_frontier() {
+        let depth = 3;
+        let mut frontier = MerkleFrontier::new(depth);
+        let mut sparse = SparseMerkleTree::with_depth(depth);
+        (0..1 << depth).for_each(|position| {
+            frontier.append(leaf(position));
+            sparse.insert(BlsScalar::from(position), leaf(position));
+        });
+        assert_eq!(frontier.size(), 1 << depth);
+        assert_eq!(frontier.root(), sparse.root());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_append_to_full_frontier() {
+        let mut frontier = MerkleFrontier::new(1);
+        (0..3).for_each(|position| {
+            frontier.append(leaf(position));
+        });
+    }
+
+    #[test]
+    fn test_tracked_witnesses() {
+        let depth = 5;
+        let mut frontier = MerkleFrontier::new(depth);
+        let tracked = [0, 3, 4, 13];
+
+        (0..20).for_each(|position| {
+            match tracked.contains(&position) {
+                true => frontier.append_tracked(leaf(position)),
+                false => frontier.append(leaf(position)),
+            };
+
+            // Every witness taken so far opens the current root
+            frontier.tracked_positions().iter().for_each(|tracked| {
+                let witness = frontier.witness(*tracked).unwrap();
+                assert_eq!(witness.leaf, leaf(*tracked));
+                assert_eq!(witness.siblings.len(), depth);
+                assert_eq!(witness.root(), frontier.root());
+            });
+        });
+
+        frontier.untrack(3);
+        assert_eq!(frontier.tracked_positions(), vec![0, 4, 13]);
+        assert!(frontier.witness(3).is_none());
+        assert!(frontier.witness(5).is_none());
+    }
+}
@@ -0,0 +1,376 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Merkle inclusion proofs, checked both out of circuit and in circuit. A path
+//! is given from the leaf up to the root, where a path bit of one means that
+//! the current node is the right child, and its sibling the left child.
+
+use super::{
+    merkle_compress, merkle_compress_domain_gadget, merkle_domain_gadget,
+};
+use crate::hashing::zelbet::ZelbetConstants;
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Compute the root of a tree from a leaf and its authentication path (out of
+/// circuit)
+pub fn merkle_root_out_of(
+    leaf: BlsScalar,
+    siblings: &[BlsScalar],
+    path: &[bool],
+) -> BlsScalar {
+    assert_eq!(siblings.len(), path.len());
+    siblings
+        .iter()
+        .zip(path.iter())
+        .fold(leaf, |node, (sibling, is_right)| match is_right {
+            true => merkle_compress(*sibling, node),
+            false => merkle_compress(node, *sibling),
+        })
+}
+
+/// Selection gadget returning the (left, right) children of a node, which is
+/// (node, sibling) when the bit is zero and (sibling, node) when it is one.
+/// The bit is assumed to already be boolean constrained.
+pub fn conditional_swap_gadget(
+    composer: &mut StandardComposer,
+    bit: Variable,
+    node: Variable,
+    sibling: Variable,
+) -> (Variable, Variable) {
+    // difference = sibling - node
+    let difference = composer.big_add(
+        (BlsScalar::one(), sibling),
+        (-BlsScalar::one(), node),
+        None,
+        BlsScalar::zero(),
+        None,
+    );
+    // left = bit * (sibling - node) + node
+    let left = composer.big_mul(
+        BlsScalar::one(),
+        bit,
+        difference,
+        Some((BlsScalar::one(), node)),
+        BlsScalar::zero(),
+        None,
+    );
+    // right = node + sibling - left
+    let right = composer.big_add(
+        (BlsScalar::one(), node),
+        (BlsScalar::one(), sibling),
+        Some((-BlsScalar::one(), left)),
+        BlsScalar::zero(),
+        None,
+    );
+
+    (left, right)
+}
+
+/// Compute the root of a tree from a leaf and its authentication path in
+/// circuit, given as variables along with their values. Each path bit is
+/// boolean constrained, so every level of the tree costs the same number of
+/// gates.
+#[allow(clippy::too_many_arguments)]
+pub fn merkle_root_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    leaf: Variable,
+    leaf_value: BlsScalar,
+    siblings: &[Variable],
+    sibling_values: &[BlsScalar],
+    path: &[Variable],
+    path_values: &[bool],
+) -> Variable {
+    path.iter().for_each(|bit| {
+        composer.boolean_gate(*bit);
+    });
+    merkle_path_gadget(
+        composer,
+        constants,
+        leaf,
+        leaf_value,
+        siblings,
+        sibling_values,
+        path,
+        path_values,
+    )
+}
+
+/// Compute the root of a tree from a leaf and its authentication path in
+/// circuit, given as variables along with their values, where the path bits
+/// have already been boolean constrained by the caller
+#[allow(clippy::too_many_arguments)]
+pub fn merkle_path_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    leaf: Variable,
+    leaf_value: BlsScalar,
+    siblings: &[Variable],
+    sibling_values: &[BlsScalar],
+    path: &[Variable],
+    path_values: &[bool],
+) -> Variable {
+    assert_eq!(siblings.len(), path.len());
+    assert_eq!(sibling_values.len(), path.len());
+    assert_eq!(path_values.len(), path.len());
+    let domain = merkle_domain_gadget(composer);
+    let (root, _) = (0..path.len()).fold(
+        (leaf, leaf_value),
+        |(node, node_value), level| {
+            let sibling_value = sibling_values[level];
+            let (left, right) = conditional_swap_gadget(
+                composer,
+                path[level],
+                node,
+                siblings[level],
+            );
+            let (left_value, right_value) = match path_values[level] {
+                true => (sibling_value, node_value),
+                false => (node_value, sibling_value),
+            };
+            let parent = merkle_compress_domain_gadget(
+                composer,
+                constants,
+                domain,
+                left,
+                left_value,
+                right,
+                right_value,
+            );
+            (parent, merkle_compress(left_value, right_value))
+        },
+    );
+    root
+}
+
+/// Prove in circuit that the leaf is included in the tree with the given
+/// root, where the root is a public input of the circuit and the leaf and its
+/// authentication path are given as variables along with their values
+#[allow(clippy::too_many_arguments)]
+pub fn merkle_opening_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    leaf: Variable,
+    leaf_value: BlsScalar,
+    siblings: &[Variable],
+    sibling_values: &[BlsScalar],
+    path: &[Variable],
+    path_values: &[bool],
+    root: BlsScalar,
+) {
+    let computed_root = merkle_root_gadget(
+        composer,
+        constants,
+        leaf,
+        leaf_value,
+        siblings,
+        sibling_values,
+        path,
+        path_values,
+    );
+    composer.constrain_to_constant(
+        computed_root,
+        BlsScalar::zero(),
+        Some(-root),
+    );
+}
+
+/// Number of gates added by [`merkle_opening_gadget`] for a tree of the given
+/// depth, not counting the [`ZelbetConstants`] that are shared with the rest
+/// of the circuit
+pub fn merkle_opening_cost(depth: usize) -> usize {
+    let mut composer = StandardComposer::new();
+    let constants = ZelbetConstants::new(&mut composer);
+    let leaf = composer.add_input(BlsScalar::zero());
+    let siblings = vec![composer.add_input(BlsScalar::zero()); depth];
+    let path = vec![composer.add_input(BlsScalar::zero()); depth];
+
+    let size_before = composer.circuit_size();
+    merkle_opening_gadget(
+        &mut composer,
+        &constants,
+        leaf,
+        BlsScalar::zero(),
+        &siblings,
+        &vec![BlsScalar::zero(); depth],
+        &path,
+        &vec![false; depth],
+        BlsScalar::zero(),
+    );
+
+    composer.circuit_size() - size_before
+}
+
+/// Number of gates added by each level of [`merkle_opening_gadget`]
+pub fn merkle_level_cost() -> usize {
+    merkle_opening_cost(1) - merkle_opening_cost(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+
+    const DEPTH: usize = 3;
+
+    fn test_path() -> (BlsScalar, [BlsScalar; DEPTH], [bool; DEPTH]) {
+        let leaf = BlsScalar::from(42);
+        let siblings = [
+            BlsScalar::from(7),
+            -BlsScalar::from(11),
+            BlsScalar::from(298375439085),
+        ];
+        (leaf, siblings, [true, false, true])
+    }
+
+    #[test]
+    fn test_merkle_root_out_of() {
+        let (leaf, siblings, path) = test_path();
+        let level_1 = merkle_compress(siblings[0], leaf);
+        let level_2 = merkle_compress(level_1, siblings[1]);
+        let root = merkle_compress(siblings[2], level_2);
+        assert_eq!(merkle_root_out_of(leaf, &siblings, &path), root);
+    }
+
+    #[test]
+    fn test_merkle_opening_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let (leaf_value, sibling_values, path_values) = test_path();
+                let root = merkle_root_out_of(
+                    leaf_value,
+                    &sibling_values,
+                    &path_values,
+                );
+
+                let leaf = composer.add_input(leaf_value);
+                let siblings: Vec<Variable> = sibling_values
+                    .iter()
+                    .map(|s| composer.add_input(*s))
+                    .collect();
+                let path: Vec<Variable> = path_values
+                    .iter()
+                    .map(|b| composer.add_input(BlsScalar::from(*b as u64)))
+                    .collect();
+                merkle_opening_gadget(
+                    composer,
+                    &constants,
+                    leaf,
+                    leaf_value,
+                    &siblings,
+                    &sibling_values,
+                    &path,
+                    &path_values,
+                    root,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            5000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_merkle_opening_gadget_wrong_root_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let (leaf_value, sibling_values, mut path_values) = test_path();
+                let root = merkle_root_out_of(
+                    leaf_value,
+                    &sibling_values,
+                    &path_values,
+                );
+                // Open the leaf along a different path to the one it was
+                // inserted at
+                path_values[1] = !path_values[1];
+
+                let leaf = composer.add_input(leaf_value);
+                let siblings: Vec<Variable> = sibling_values
+                    .iter()
+                    .map(|s| composer.add_input(*s))
+                    .collect();
+                let path: Vec<Variable> = path_values
+                    .iter()
+                    .map(|b| composer.add_input(BlsScalar::from(*b as u64)))
+                    .collect();
+                merkle_opening_gadget(
+                    composer,
+                    &constants,
+                    leaf,
+                    leaf_value,
+                    &siblings,
+                    &sibling_values,
+                    &path,
+                    &path_values,
+                    root,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            5000,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_merkle_opening_non_boolean_path_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let leaf = composer.add_input(BlsScalar::from(42));
+                let sibling = composer.add_input(BlsScalar::from(7));
+                // A path value of two is not a valid direction
+                let path = composer.add_input(BlsScalar::from(2));
+                let root =
+                    merkle_compress(BlsScalar::from(42), BlsScalar::from(7));
+                merkle_opening_gadget(
+                    composer,
+                    &constants,
+                    leaf,
+                    BlsScalar::from(42),
+                    &[sibling],
+                    &[BlsScalar::from(7)],
+                    &[path],
+                    &[false],
+                    root,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            3000,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_merkle_opening_cost_per_level() {
+        let level_cost = merkle_level_cost();
+        // Only the public root constraint is independent of the depth
+        let fixed_cost = merkle_opening_cost(0);
+        (1..=4).for_each(|depth| {
+            assert_eq!(
+                merkle_opening_cost(depth),
+                fixed_cost + depth * level_cost
+            );
+        });
+    }
+}
@@ -0,0 +1,596 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Sparse Merkle tree keyed by BLS scalars. The position of a key is given by
+//! the bits of its canonical representation, so a tree of full depth has a
+//! leaf for every element of the field. Empty leaves hold zero, so a key is in
+//! the tree exactly when its leaf is non-zero, which allows both membership
+//! and non-membership to be proven against the same root.
+
+use super::opening::merkle_path_gadget;
+use super::{empty_subtree_hashes, merkle_compress};
+use crate::hashing::gadgets::{scalar_bits_gadget, SCALAR_BITS};
+use crate::hashing::zelbet::ZelbetConstants;
+use bigint::U256 as u256;
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+use std::collections::HashMap;
+
+/// Depth of a tree with a leaf for every key
+pub const SPARSE_MERKLE_DEPTH: usize = SCALAR_BITS;
+
+/// Position of a key in a tree of the given depth. Keys must fit in the depth
+/// of the tree, so that two keys are never assigned the same leaf.
+fn key_index(key: BlsScalar, depth: usize) -> u256 {
+    assert!(depth <= SPARSE_MERKLE_DEPTH);
+    checked_key_index(key, depth).expect("key does not fit in the tree")
+}
+
+/// Position of a key in a tree of the given depth, or `None` when the depth
+/// is too large or the key does not fit in it
+fn checked_key_index(key: BlsScalar, depth: usize) -> Option<u256> {
+    let index = u256(key.reduce().0);
+    match depth <= SPARSE_MERKLE_DEPTH && index.bits() <= depth {
+        true => Some(index),
+        false => None,
+    }
+}
+
+/// Sparse Merkle tree, storing only the nodes that differ from the hash of an
+/// empty subtree
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree {
+    depth: usize,
+    empty: Vec<BlsScalar>,
+    nodes: HashMap<(usize, u256), BlsScalar>,
+}
+
+impl Default for SparseMerkleTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparseMerkleTree {
+    /// Create an empty tree with a leaf for every BLS scalar
+    pub fn new() -> Self {
+        Self::with_depth(SPARSE_MERKLE_DEPTH)
+    }
+
+    /// Create an empty tree of the given depth, which can only hold keys
+    /// smaller than 2^depth
+    pub fn with_depth(depth: usize) -> Self {
+        assert!(depth <= SPARSE_MERKLE_DEPTH);
+        SparseMerkleTree {
+            depth,
+            empty: empty_subtree_hashes(depth),
+            nodes: HashMap::new(),
+        }
+    }
+
+    /// Depth of the tree
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Precomputed hashes of the empty subtrees, as used to verify proofs
+    pub fn empty_hashes(&self) -> &[BlsScalar] {
+        &self.empty
+    }
+
+    /// Current root of the tree
+    pub fn root(&self) -> BlsScalar {
+        self.node(self.depth, u256::zero())
+    }
+
+    fn node(&self, level: usize, index: u256) -> BlsScalar {
+        *self
+            .nodes
+            .get(&(level, index))
+            .unwrap_or(&self.empty[level])
+    }
+
+    fn set_node(&mut self, level: usize, index: u256, value: BlsScalar) {
+        if value == self.empty[level] {
+            self.nodes.remove(&(level, index));
+        } else {
+            self.nodes.insert((level, index), value);
+        }
+    }
+
+    /// Value stored at the key, which is zero if the key is not in the tree
+    pub fn get(&self, key: BlsScalar) -> BlsScalar {
+        self.node(0, key_index(key, self.depth))
+    }
+
+    /// Whether the key is in the tree
+    pub fn contains(&self, key: BlsScalar) -> bool {
+        self.get(key) != BlsScalar::zero()
+    }
+
+    /// Store a non-zero value at the key, updating the path to the root
+    pub fn insert(&mut self, key: BlsScalar, value: BlsScalar) {
+        assert!(value != BlsScalar::zero(), "zero marks an empty leaf");
+        self.update(key, value);
+    }
+
+    /// Remove the key from the tree, updating the path to the root
+    pub fn remove(&mut self, key: BlsScalar) {
+        self.update(key, BlsScalar::zero());
+    }
+
+    fn update(&mut self, key: BlsScalar, value: BlsScalar) {
+        let index = key_index(key, self.depth);
+        self.set_node(0, index, value);
+
+        let mut node = value;
+        (0..self.depth).for_each(|level| {
+            let node_index = index >> level;
+            let sibling = self.node(level, node_index ^ u256::one());
+            node = match node_index.bit(0) {
+                true => merkle_compress(sibling, node),
+                false => merkle_compress(node, sibling),
+            };
+            self.set_node(level + 1, node_index >> 1, node);
+        });
+    }
+
+    /// Authentication path for the key, which proves membership if the key is
+    /// in the tree and non-membership otherwise
+    pub fn prove(&self, key: BlsScalar) -> SparseMerkleProof {
+        let index = key_index(key, self.depth);
+        let mut bitmap = u256::zero();
+        let mut siblings = Vec::new();
+
+        (0..self.depth).for_each(|level| {
+            let sibling = self.node(level, (index >> level) ^ u256::one());
+            if sibling != self.empty[level] {
+                bitmap = bitmap | (u256::one() << level);
+                siblings.push(sibling);
+            }
+        });
+
+        SparseMerkleProof { bitmap, siblings }
+    }
+}
+
+/// Compressed authentication path in a sparse Merkle tree. Siblings that are
+/// the hash of an empty subtree are omitted, and the bitmap records the levels
+/// at which a sibling is present.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMerkleProof {
+    /// Bit `l` is set when the sibling at level `l` is stored in the proof
+    pub bitmap: u256,
+    /// The non-empty siblings, from the leaf level upwards
+    pub siblings: Vec<BlsScalar>,
+}
+
+impl SparseMerkleProof {
+    /// Expand the proof into the full list of siblings, from the leaf level
+    /// upwards, where `empty` are the empty subtree hashes of the tree. Returns
+    /// `None` when the bitmap does not match the stored siblings, or when
+    /// `empty` does not describe a tree.
+    pub fn decompress(&self, empty: &[BlsScalar]) -> Option<Vec<BlsScalar>> {
+        let depth = empty.len().checked_sub(1)?;
+        if depth > SPARSE_MERKLE_DEPTH
+            || (depth..256).any(|level| self.bitmap.bit(level))
+        {
+            return None;
+        }
+
+        let mut stored = self.siblings.iter();
+        let siblings = (0..depth)
+            .map(|level| match self.bitmap.bit(level) {
+                true => stored.next().copied(),
+                false => Some(empty[level]),
+            })
+            .collect::<Option<Vec<BlsScalar>>>()?;
+        match stored.next() {
+            Some(_) => None,
+            None => Some(siblings),
+        }
+    }
+
+    /// Root of the tree in which the leaf of the key holds the value, or
+    /// `None` when the proof is malformed or the key does not fit in the tree
+    pub fn compute_root(
+        &self,
+        empty: &[BlsScalar],
+        key: BlsScalar,
+        value: BlsScalar,
+    ) -> Option<BlsScalar> {
+        let depth = empty.len().checked_sub(1)?;
+        let index = checked_key_index(key, depth)?;
+        let siblings = self.decompress(empty)?;
+        Some(siblings.iter().enumerate().fold(
+            value,
+            |node, (level, sibling)| match index.bit(level) {
+                true => merkle_compress(*sibling, node),
+                false => merkle_compress(node, *sibling),
+            },
+        ))
+    }
+
+    /// Check that the key is stored in the tree with the given value
+    pub fn verify_membership(
+        &self,
+        empty: &[BlsScalar],
+        root: BlsScalar,
+        key: BlsScalar,
+        value: BlsScalar,
+    ) -> bool {
+        value != BlsScalar::zero()
+            && self.compute_root(empty, key, value) == Some(root)
+    }
+
+    /// Check that the key is not in the tree, i.e. that its leaf is empty
+    pub fn verify_non_membership(
+        &self,
+        empty: &[BlsScalar],
+        root: BlsScalar,
+        key: BlsScalar,
+    ) -> bool {
+        self.compute_root(empty, key, BlsScalar::zero()) == Some(root)
+    }
+}
+
+/// Compute the root of a sparse Merkle tree in circuit from a key, the value
+/// at its leaf and the full list of siblings (see
+/// [`SparseMerkleProof::decompress`]). The scalars are the witness values of
+/// the variables.
+#[allow(clippy::too_many_arguments)]
+pub fn sparse_merkle_root_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    key: Variable,
+    key_scalar: BlsScalar,
+    value: Variable,
+    value_scalar: BlsScalar,
+    siblings: &[Variable],
+    sibling_scalars: &[BlsScalar],
+) -> Variable {
+    let depth = siblings.len();
+    let path = scalar_bits_gadget(composer, key, key_scalar, depth);
+    let index = key_index(key_scalar, depth);
+    let path_bits: Vec<bool> = (0..depth).map(|bit| index.bit(bit)).collect();
+    merkle_path_gadget(
+        composer,
+        constants,
+        value,
+        value_scalar,
+        siblings,
+        sibling_scalars,
+        &path,
+        &path_bits,
+    )
+}
+
+/// Prove in circuit that the key is stored in the tree with the given value,
+/// where the root is a public input of the circuit. The scalars of the key,
+/// value and siblings are the witness values of their variables.
+#[allow(clippy::too_many_arguments)]
+pub fn sparse_merkle_membership_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    key: Variable,
+    key_scalar: BlsScalar,
+    value: Variable,
+    value_scalar: BlsScalar,
+    siblings: &[Variable],
+    sibling_scalars: &[BlsScalar],
+    root: BlsScalar,
+) {
+    // A member has a non-zero leaf, which is shown by the existence of an
+    // inverse of the value
+    let value_inverse =
+        composer.add_input(value_scalar.invert().unwrap_or(BlsScalar::zero()));
+    let product = composer.mul(
+        BlsScalar::one(),
+        value,
+        value_inverse,
+        BlsScalar::zero(),
+        None,
+    );
+    composer.constrain_to_constant(product, BlsScalar::one(), None);
+
+    let computed_root = sparse_merkle_root_gadget(
+        composer,
+        constants,
+        key,
+        key_scalar,
+        value,
+        value_scalar,
+        siblings,
+        sibling_scalars,
+    );
+    composer.constrain_to_constant(
+        computed_root,
+        BlsScalar::zero(),
+        Some(-root),
+    );
+}
+
+/// Prove in circuit that the key is not in the tree, i.e. that its leaf is
+/// empty, where the root is a public input of the circuit. The scalars of the
+/// key and siblings are the witness values of their variables.
+pub fn sparse_merkle_non_membership_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    key: Variable,
+    key_scalar: BlsScalar,
+    siblings: &[Variable],
+    sibling_scalars: &[BlsScalar],
+    root: BlsScalar,
+) {
+    let computed_root = sparse_merkle_root_gadget(
+        composer,
+        constants,
+        key,
+        key_scalar,
+        constants.zero,
+        BlsScalar::zero(),
+        siblings,
+        sibling_scalars,
+    );
+    composer.constrain_to_constant(
+        computed_root,
+        BlsScalar::zero(),
+        Some(-root),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+
+    const TEST_DEPTH: usize = 4;
+
+    fn test_tree() -> SparseMerkleTree {
+        let mut tree = SparseMerkleTree::with_depth(TEST_DEPTH);
+        tree.insert(BlsScalar::from(3), BlsScalar::from(3));
+        tree.insert(BlsScalar::from(12), BlsScalar::from(12));
+        tree
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree = SparseMerkleTree::new();
+        let empty = empty_subtree_hashes(SPARSE_MERKLE_DEPTH);
+        assert_eq!(tree.root(), empty[SPARSE_MERKLE_DEPTH]);
+        assert!(!tree.contains(-BlsScalar::one()));
+
+        let proof = tree.prove(-BlsScalar::one());
+        assert!(proof.siblings.is_empty());
+        assert!(proof.verify_non_membership(
+            tree.empty_hashes(),
+            tree.root(),
+            -BlsScalar::one()
+        ));
+    }
+
+    #[test]
+    fn test_membership_and_non_membership() {
+        let mut tree = SparseMerkleTree::new();
+        let keys = [
+            BlsScalar::from(5),
+            -BlsScalar::one(),
+            BlsScalar::from(298375439085),
+        ];
+        keys.iter().for_each(|key| tree.insert(*key, *key));
+        let root = tree.root();
+        let empty = tree.empty_hashes();
+
+        keys.iter().for_each(|key| {
+            assert!(tree.contains(*key));
+            let proof = tree.prove(*key);
+            // Only the levels at which the keys branch apart have non-empty
+            // siblings
+            assert!(proof.siblings.len() < keys.len());
+            assert!(proof.verify_membership(empty, root, *key, *key));
+            assert!(!proof.verify_membership(
+                empty,
+                root,
+                *key,
+                BlsScalar::from(7)
+            ));
+            assert!(!proof.verify_non_membership(empty, root, *key));
+        });
+
+        let absent = BlsScalar::from(4);
+        let proof = tree.prove(absent);
+        assert!(proof.verify_non_membership(empty, root, absent));
+        assert!(!proof.verify_membership(empty, root, absent, absent));
+
+        // Dropping a sibling from the proof must not verify
+        let mut proof = tree.prove(keys[0]);
+        proof.siblings.pop();
+        assert!(!proof.verify_membership(empty, root, keys[0], keys[0]));
+    }
+
+    #[test]
+    fn test_malformed_proofs() {
+        let tree = test_tree();
+        let root = tree.root();
+        let empty = tree.empty_hashes();
+        let key = BlsScalar::from(3);
+        let proof = tree.prove(key);
+        assert!(proof.decompress(empty).is_some());
+
+        // A level marked in the bitmap without its sibling
+        let mut missing = proof.clone();
+        missing.siblings.clear();
+        assert!(missing.decompress(empty).is_none());
+        assert!(missing.compute_root(empty, key, key).is_none());
+        assert!(!missing.verify_membership(empty, root, key, key));
+        assert!(!missing.verify_non_membership(empty, root, key));
+
+        // A sibling that is not marked in the bitmap
+        let mut extra = proof.clone();
+        extra.siblings.push(BlsScalar::one());
+        assert!(extra.decompress(empty).is_none());
+        assert!(!extra.verify_membership(empty, root, key, key));
+
+        // A level above the depth of the tree
+        let mut too_deep = proof;
+        too_deep.bitmap = too_deep.bitmap | (u256::one() << TEST_DEPTH);
+        too_deep.siblings.push(BlsScalar::one());
+        assert!(too_deep.decompress(empty).is_none());
+        assert!(!too_deep.verify_membership(empty, root, key, key));
+    }
+
+    #[test]
+    fn test_malformed_inputs() {
+        let tree = test_tree();
+        let root = tree.root();
+        let empty = tree.empty_hashes();
+        let proof = tree.prove(BlsScalar::from(3));
+
+        // A key wider than the tree
+        let wide = BlsScalar::from(1 << TEST_DEPTH);
+        assert!(proof.compute_root(empty, wide, wide).is_none());
+        assert!(!proof.verify_membership(empty, root, wide, wide));
+        assert!(!proof.verify_non_membership(empty, root, wide));
+
+        // No empty subtree hashes, or more than for a tree of full depth
+        let key = BlsScalar::from(3);
+        assert!(proof.decompress(&[]).is_none());
+        assert!(proof.compute_root(&[], key, key).is_none());
+        assert!(!proof.verify_membership(&[], root, key, key));
+        assert!(!proof.verify_non_membership(&[], root, key));
+        let too_many = vec![BlsScalar::zero(); SPARSE_MERKLE_DEPTH + 2];
+        assert!(proof.decompress(&too_many).is_none());
+        assert!(!proof.verify_non_membership(&too_many, root, key));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut tree = test_tree();
+        let root = tree.root();
+        tree.insert(BlsScalar::from(7), BlsScalar::one());
+        assert_ne!(tree.root(), root);
+        tree.remove(BlsScalar::from(7));
+        assert_eq!(tree.root(), root);
+
+        tree.remove(BlsScalar::from(3));
+        tree.remove(BlsScalar::from(12));
+        assert_eq!(tree.root(), tree.empty_hashes()[TEST_DEPTH]);
+    }
+
+    #[test]
+    fn test_sparse_merkle_membership_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let tree = test_tree();
+                let key_scalar = BlsScalar::from(12);
+                let sibling_scalars = tree
+                    .prove(key_scalar)
+                    .decompress(tree.empty_hashes())
+                    .unwrap();
+                let siblings: Vec<Variable> = sibling_scalars
+                    .iter()
+                    .map(|sibling| composer.add_input(*sibling))
+                    .collect();
+                let key = composer.add_input(key_scalar);
+                let value = composer.add_input(key_scalar);
+                sparse_merkle_membership_gadget(
+                    composer,
+                    &constants,
+                    key,
+                    key_scalar,
+                    value,
+                    key_scalar,
+                    &siblings,
+                    &sibling_scalars,
+                    tree.root(),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            9000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_sparse_merkle_non_membership_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let tree = test_tree();
+                let key_scalar = BlsScalar::from(9);
+                let sibling_scalars = tree
+                    .prove(key_scalar)
+                    .decompress(tree.empty_hashes())
+                    .unwrap();
+                let siblings: Vec<Variable> = sibling_scalars
+                    .iter()
+                    .map(|sibling| composer.add_input(*sibling))
+                    .collect();
+                let key = composer.add_input(key_scalar);
+                sparse_merkle_non_membership_gadget(
+                    composer,
+                    &constants,
+                    key,
+                    key_scalar,
+                    &siblings,
+                    &sibling_scalars,
+                    tree.root(),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            9000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_sparse_merkle_non_membership_of_member_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let tree = test_tree();
+                let key_scalar = BlsScalar::from(3);
+                let sibling_scalars = tree
+                    .prove(key_scalar)
+                    .decompress(tree.empty_hashes())
+                    .unwrap();
+                let siblings: Vec<Variable> = sibling_scalars
+                    .iter()
+                    .map(|sibling| composer.add_input(*sibling))
+                    .collect();
+                let key = composer.add_input(key_scalar);
+                sparse_merkle_non_membership_gadget(
+                    composer,
+                    &constants,
+                    key,
+                    key_scalar,
+                    &siblings,
+                    &sibling_scalars,
+                    tree.root(),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            9000,
+        );
+        assert!(res.is_err());
+    }
+}
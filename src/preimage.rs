@@ -0,0 +1,198 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Ready made circuit proving the knowledge of a preimage of the Zelbet sponge,
+//! that is of `x` such that `sponge_zelbet_out_of(x, 1)[0] = h` where `h` is
+//! the only public input. The length of `x` is part of the circuit.
+//!
+//! The helpers below set up the public parameters, compile the keys, prove and
+//! verify. Prover keys and verifier data serialize with `to_var_bytes` and
+//! `from_slice`, and proofs with `to_bytes` and `from_bytes`, so they can be
+//! cached.
+
+use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+use crate::hashing::sponge::{sponge_zelbet_gadget, sponge_zelbet_out_of};
+use dusk_plonk::circuit::{Circuit, PublicInputValue, VerifierData};
+use dusk_plonk::error::Error;
+use dusk_plonk::prelude::*;
+use rand_core::{CryptoRng, RngCore};
+
+/// Label used to initialise the transcripts of proofs
+pub const PREIMAGE_TRANSCRIPT_INIT: &[u8] = b"rc.preimage";
+
+/// Circuit proving the knowledge of a preimage of a public hash
+#[derive(Debug, Clone, Default)]
+pub struct PreimageCircuit {
+    preimage: Vec<BlsScalar>,
+    hash: BlsScalar,
+}
+
+impl PreimageCircuit {
+    /// Circuit with the given preimage as witness
+    pub fn new(preimage: Vec<BlsScalar>) -> Self {
+        let hash = sponge_zelbet_out_of(preimage.clone(), 1)[0];
+        PreimageCircuit { preimage, hash }
+    }
+
+    /// Circuit for preimages of the given length with an arbitrary witness,
+    /// to compile the keys
+    pub fn with_length(length: usize) -> Self {
+        PreimageCircuit::new(vec![BlsScalar::zero(); length])
+    }
+
+    /// Hash of the preimage, the public input of the circuit
+    pub fn hash(&self) -> BlsScalar {
+        self.hash
+    }
+}
+
+impl Circuit for PreimageCircuit {
+    const CIRCUIT_ID: [u8; 32] = [
+        0x72, 0x63, 0x2e, 0x70, 0x72, 0x65, 0x69, 0x6d, 0x61, 0x67, 0x65, 0x2e,
+        0x63, 0x69, 0x72, 0x63, 0x75, 0x69, 0x74, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    fn gadget(&mut self, composer: &mut StandardComposer) -> Result<(), Error> {
+        let hash_table = bar_lookup_table();
+        composer.append_lookup_table(&hash_table);
+
+        let preimage: Vec<Variable> = self
+            .preimage
+            .iter()
+            .map(|x| composer.add_input(*x))
+            .collect();
+        let computed =
+            sponge_zelbet_gadget(composer, preimage, &self.preimage, 1)[0];
+        composer.constrain_to_constant(
+            computed,
+            BlsScalar::zero(),
+            Some(-self.hash),
+        );
+
+        pad_lookup_gates(composer);
+
+        Ok(())
+    }
+
+    // The commit key is trimmed to twice the size of the circuit, as the
+    // lookup polynomials need the extra degree
+    fn padded_circuit_size(&self) -> usize {
+        let mut composer = StandardComposer::new();
+        self.clone()
+            .gadget(&mut composer)
+            .expect("the gadget does not fail");
+        2 * composer.circuit_size().next_power_of_two()
+    }
+}
+
+/// Generate public parameters large enough for preimages of the given length.
+/// In production the parameters come from a trusted setup instead.
+pub fn setup<R: RngCore + CryptoRng>(
+    length: usize,
+    rng: &mut R,
+) -> Result<PublicParameters, Error> {
+    PublicParameters::setup(
+        PreimageCircuit::with_length(length).padded_circuit_size(),
+        rng,
+    )
+}
+
+/// Compile the prover key and verifier data for preimages of the given length
+pub fn compile(
+    public_parameters: &PublicParameters,
+    length: usize,
+) -> Result<(ProverKey, VerifierData), Error> {
+    PreimageCircuit::with_length(length).compile(public_parameters)
+}
+
+/// Prove the knowledge of the preimage, returning the proof and the hash that
+/// it is checked against
+pub fn prove(
+    public_parameters: &PublicParameters,
+    prover_key: &ProverKey,
+    preimage: Vec<BlsScalar>,
+) -> Result<(Proof, BlsScalar), Error> {
+    let mut circuit = PreimageCircuit::new(preimage);
+    let proof = circuit.prove(
+        public_parameters,
+        prover_key,
+        PREIMAGE_TRANSCRIPT_INIT,
+    )?;
+    Ok((proof, circuit.hash()))
+}
+
+/// Verify a proof of the knowledge of a preimage of the hash
+pub fn verify(
+    public_parameters: &PublicParameters,
+    verifier_data: &VerifierData,
+    proof: &Proof,
+    hash: BlsScalar,
+) -> Result<(), Error> {
+    let public_inputs: Vec<PublicInputValue> = vec![hash.into()];
+    PreimageCircuit::verify(
+        public_parameters,
+        verifier_data,
+        proof,
+        &public_inputs,
+        PREIMAGE_TRANSCRIPT_INIT,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dusk_bytes::Serializable;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_preimage_prove_and_verify() -> Result<(), Error> {
+        let public_parameters = setup(2, &mut OsRng)?;
+        let (prover_key, verifier_data) = compile(&public_parameters, 2)?;
+
+        let preimage = vec![BlsScalar::from(3), -BlsScalar::from(7)];
+        let (proof, hash) = prove(&public_parameters, &prover_key, preimage)?;
+        assert_eq!(
+            hash,
+            sponge_zelbet_out_of(
+                vec![BlsScalar::from(3), -BlsScalar::from(7)],
+                1
+            )[0]
+        );
+        verify(&public_parameters, &verifier_data, &proof, hash)?;
+
+        // The proof does not verify for another hash
+        assert!(verify(
+            &public_parameters,
+            &verifier_data,
+            &proof,
+            hash + BlsScalar::one()
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_preimage_serialization() -> Result<(), Error> {
+        let public_parameters = setup(3, &mut OsRng)?;
+        let (prover_key, verifier_data) = compile(&public_parameters, 3)?;
+
+        // Keys and proofs survive a round trip through bytes
+        let prover_key = ProverKey::from_slice(&prover_key.to_var_bytes())?;
+        let verifier_data =
+            VerifierData::from_slice(&verifier_data.to_var_bytes())?;
+        let (proof, hash) = prove(
+            &public_parameters,
+            &prover_key,
+            vec![BlsScalar::from(1), BlsScalar::from(2), BlsScalar::from(3)],
+        )?;
+        let proof =
+            Proof::from_bytes(&proof.to_bytes()).expect("valid proof bytes");
+
+        verify(&public_parameters, &verifier_data, &proof, hash)
+    }
+}
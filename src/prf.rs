@@ -0,0 +1,290 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Keyed pseudorandom function built on the domain separated Zelbet sponge,
+//! and the derivation of nullifiers from it. The input to the sponge is the
+//! [`length_prefixed_input`] `[key, input length, input...]`, and the capacity
+//! is set to the tag of [`PRF_DOMAIN`], so outputs of the PRF can never
+//! collide with commitments or plain hashes of the same elements.
+
+use crate::commitment::commitment_gadget;
+use crate::hashing::sponge::{
+    domain_tag, length_prefixed_input, length_prefixed_input_gadget,
+    sponge_zelbet_domain_gadget, sponge_zelbet_domain_out_of,
+};
+use crate::hashing::zelbet::ZelbetConstants;
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Label of the sponge domain used for the PRF
+pub const PRF_DOMAIN: &[u8] = b"rc.prf";
+
+/// Evaluate the PRF under the given key (out of circuit)
+pub fn prf(key: BlsScalar, input: &[BlsScalar]) -> BlsScalar {
+    let state = length_prefixed_input(key, input);
+    sponge_zelbet_domain_out_of(domain_tag(PRF_DOMAIN), state, 1)[0]
+}
+
+/// Nullifier of the note with the given position and commitment, derived
+/// under the nullifier key `nk` as `prf(nk, [position, commitment])`
+pub fn nullifier(
+    nk: BlsScalar,
+    position: u64,
+    commitment: BlsScalar,
+) -> BlsScalar {
+    prf(nk, &[BlsScalar::from(position), commitment])
+}
+
+/// Evaluate the PRF in circuit, where the key and the input are given as
+/// variables along with their values.
+pub fn prf_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    key: Variable,
+    key_value: BlsScalar,
+    input: &[Variable],
+    input_values: &[BlsScalar],
+) -> Variable {
+    let (state, state_values) = length_prefixed_input_gadget(
+        composer,
+        key,
+        key_value,
+        input,
+        input_values,
+    );
+    sponge_zelbet_domain_gadget(
+        composer,
+        constants,
+        domain_tag(PRF_DOMAIN),
+        state,
+        &state_values,
+        1,
+    )[0]
+}
+
+/// Compute the nullifier of a note in circuit, where the key, the position
+/// and the commitment of the note are given along with their values
+#[allow(clippy::too_many_arguments)]
+pub fn nullifier_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    nk: Variable,
+    nk_value: BlsScalar,
+    position: Variable,
+    position_value: u64,
+    commitment: Variable,
+    commitment_value: BlsScalar,
+) -> Variable {
+    prf_gadget(
+        composer,
+        constants,
+        nk,
+        nk_value,
+        &[position, commitment],
+        &[BlsScalar::from(position_value), commitment_value],
+    )
+}
+
+/// Prove in circuit that the nullifier, a public input, is derived from the
+/// note with the given position and commitment under a nullifier key that
+/// opens `nk_commitment`, also a public input. The key commitment is computed
+/// with [`commit`](crate::commitment::commit) on the single element `nk`. The
+/// witnesses are given as variables along with their values.
+#[allow(clippy::too_many_arguments)]
+pub fn nullifier_derivation_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    nk: Variable,
+    nk_value: BlsScalar,
+    nk_blinding: Variable,
+    nk_blinding_value: BlsScalar,
+    nk_commitment: BlsScalar,
+    position: Variable,
+    position_value: u64,
+    commitment: Variable,
+    commitment_value: BlsScalar,
+    nullifier: BlsScalar,
+) {
+    let computed_commitment = commitment_gadget(
+        composer,
+        constants,
+        &[nk],
+        &[nk_value],
+        nk_blinding,
+        nk_blinding_value,
+    );
+    composer.constrain_to_constant(
+        computed_commitment,
+        BlsScalar::zero(),
+        Some(-nk_commitment),
+    );
+
+    let computed = nullifier_gadget(
+        composer,
+        constants,
+        nk,
+        nk_value,
+        position,
+        position_value,
+        commitment,
+        commitment_value,
+    );
+    composer.constrain_to_constant(
+        computed,
+        BlsScalar::zero(),
+        Some(-nullifier),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commitment::commit;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+    use crate::hashing::sponge::sponge_zelbet_out_of;
+
+    #[test]
+    fn test_prf_vectors() {
+        assert_eq!(
+            prf(
+                BlsScalar::from(5),
+                &[BlsScalar::from(1), BlsScalar::from(2)]
+            ),
+            BlsScalar([
+                17876834220273790949,
+                11518818522879548618,
+                14741000415635067013,
+                4876760372764840869,
+            ])
+        );
+        assert_eq!(
+            prf(-BlsScalar::one(), &[]),
+            BlsScalar([
+                14564208593011091781,
+                9441347906156625025,
+                539403364739291531,
+                952729679593773616,
+            ])
+        );
+        assert_eq!(
+            nullifier(BlsScalar::from(7), 3, BlsScalar::from(11)),
+            BlsScalar([
+                1903116105601029751,
+                11988985377201160370,
+                2218358660297240573,
+                1078188253070423711,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_prf_separation() {
+        let key = BlsScalar::from(5);
+        let input = [BlsScalar::from(1), BlsScalar::from(2)];
+        assert_ne!(prf(key, &input), prf(BlsScalar::from(6), &input));
+        assert_ne!(
+            prf(key, &input[..1]),
+            prf(key, &[input[0], BlsScalar::one()])
+        );
+
+        // The same elements give different outputs as a commitment, a plain
+        // hash and a PRF evaluation
+        assert_ne!(prf(key, &input), commit(&input, key));
+        assert_ne!(
+            prf(key, &input),
+            sponge_zelbet_out_of(
+                vec![key, BlsScalar::from(2), input[0], input[1]],
+                1
+            )[0]
+        );
+
+        // Nullifiers of distinct notes are distinct
+        assert_ne!(
+            nullifier(key, 0, BlsScalar::from(11)),
+            nullifier(key, 1, BlsScalar::from(11))
+        );
+    }
+
+    #[test]
+    fn test_nullifier_derivation_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let nk_scalar = BlsScalar::from(7);
+                let nk_blinding_scalar = BlsScalar::from(99);
+                let commitment_scalar = BlsScalar::from(11);
+
+                let nk = composer.add_input(nk_scalar);
+                let nk_blinding = composer.add_input(nk_blinding_scalar);
+                let position = composer.add_input(BlsScalar::from(3));
+                let commitment = composer.add_input(commitment_scalar);
+                nullifier_derivation_gadget(
+                    composer,
+                    &constants,
+                    nk,
+                    nk_scalar,
+                    nk_blinding,
+                    nk_blinding_scalar,
+                    commit(&[nk_scalar], nk_blinding_scalar),
+                    position,
+                    3,
+                    commitment,
+                    commitment_scalar,
+                    nullifier(nk_scalar, 3, commitment_scalar),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            9000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_nullifier_derivation_gadget_wrong_key_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let nk_blinding_scalar = BlsScalar::from(99);
+                let commitment_scalar = BlsScalar::from(11);
+
+                // The nullifier is derived under a key other than the
+                // committed one
+                let nk = composer.add_input(BlsScalar::from(8));
+                let nk_blinding = composer.add_input(nk_blinding_scalar);
+                let position = composer.add_input(BlsScalar::from(3));
+                let commitment = composer.add_input(commitment_scalar);
+                nullifier_derivation_gadget(
+                    composer,
+                    &constants,
+                    nk,
+                    BlsScalar::from(8),
+                    nk_blinding,
+                    nk_blinding_scalar,
+                    commit(&[BlsScalar::from(7)], nk_blinding_scalar),
+                    position,
+                    3,
+                    commitment,
+                    commitment_scalar,
+                    nullifier(BlsScalar::from(8), 3, commitment_scalar),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            9000,
+        );
+        assert!(res.is_err());
+    }
+}
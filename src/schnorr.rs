@@ -0,0 +1,383 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Schnorr signatures over BLS scalar messages on the Jubjub curve, with the
+//! challenge computed by the domain separated Zelbet sponge.
+//!
+//! A signature on `m` under the key `sk`, with public key `PK = sk·G`, is a
+//! pair `(u, R)` with `R = r·G` and `u = r - c·sk`, where the challenge `c`
+//! is the hash of `[R.x, R.y, PK.x, PK.y, m]` truncated to 250 bits. It is
+//! valid when `u·G + c·PK = R`. The nonce `r` is derived from the secret key
+//! and the message, so signing is deterministic.
+
+use crate::hashing::gadgets::{scalar_bits_gadget, SCALAR_BITS};
+use crate::hashing::sponge::{
+    domain_tag, sponge_zelbet_domain_gadget, sponge_zelbet_domain_out_of,
+};
+use crate::hashing::zelbet::ZelbetConstants;
+use dusk_bytes::Serializable;
+use dusk_jubjub::{
+    JubJubAffine, JubJubExtended, JubJubScalar, GENERATOR_EXTENDED,
+};
+use dusk_plonk::{
+    constraint_system::{ecc::Point, StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+use rand_core::{CryptoRng, RngCore};
+
+/// Label of the sponge domain used for challenges
+pub const SCHNORR_DOMAIN: &[u8] = b"rc.schnorr";
+
+/// Label of the sponge domain used for the derivation of nonces
+pub const SCHNORR_NONCE_DOMAIN: &[u8] = b"rc.schnorr.nonce";
+
+/// Number of bits kept from the hash for the challenge, so that it is a
+/// canonical Jubjub scalar and fits the scalar multiplication of the composer
+pub const CHALLENGE_BITS: usize = 250;
+
+pub(crate) fn to_bls_scalar(scalar: &JubJubScalar) -> BlsScalar {
+    BlsScalar::from_bytes(&scalar.to_bytes())
+        .expect("the Jubjub order is smaller than the BLS modulus")
+}
+
+/// Full hash from which the challenge of a signature is truncated
+fn challenge_hash(
+    r: &JubJubAffine,
+    public_key: &JubJubAffine,
+    message: BlsScalar,
+) -> BlsScalar {
+    sponge_zelbet_domain_out_of(
+        domain_tag(SCHNORR_DOMAIN),
+        vec![
+            r.get_x(),
+            r.get_y(),
+            public_key.get_x(),
+            public_key.get_y(),
+            message,
+        ],
+        1,
+    )[0]
+}
+
+/// Truncate a hash to [`CHALLENGE_BITS`] bits, giving a Jubjub scalar
+pub(crate) fn truncate_challenge(hash: BlsScalar) -> JubJubScalar {
+    let mut bytes = hash.to_bytes();
+    bytes[CHALLENGE_BITS / 8] &= (1 << (CHALLENGE_BITS % 8)) - 1;
+    bytes[CHALLENGE_BITS / 8 + 1..]
+        .iter_mut()
+        .for_each(|byte| *byte = 0);
+    JubJubScalar::from_bytes(&bytes)
+        .expect("a 250 bit integer is a canonical Jubjub scalar")
+}
+
+/// Truncate a hash to [`CHALLENGE_BITS`] bits in circuit. The hash is
+/// decomposed into canonical bits, so that the truncation keeps a unique
+/// challenge.
+pub(crate) fn truncate_challenge_gadget(
+    composer: &mut StandardComposer,
+    hash: Variable,
+    hash_value: BlsScalar,
+) -> Variable {
+    let bits = scalar_bits_gadget(composer, hash, hash_value, SCALAR_BITS);
+    let zero = composer.add_witness_to_circuit_description(BlsScalar::zero());
+    bits[..CHALLENGE_BITS].iter().rev().fold(zero, |acc, bit| {
+        composer.big_add(
+            (BlsScalar::from(2), acc),
+            (BlsScalar::one(), *bit),
+            None,
+            BlsScalar::zero(),
+            None,
+        )
+    })
+}
+
+/// Challenge of a signature, the hash truncated to [`CHALLENGE_BITS`] bits
+fn challenge(
+    r: &JubJubAffine,
+    public_key: &JubJubAffine,
+    message: BlsScalar,
+) -> JubJubScalar {
+    truncate_challenge(challenge_hash(r, public_key, message))
+}
+
+/// Secret key for Schnorr signatures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecretKey(pub JubJubScalar);
+
+/// Public key for Schnorr signatures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey(pub JubJubExtended);
+
+/// Schnorr signature `(u, R)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature {
+    /// Response of the signature
+    pub u: JubJubScalar,
+    /// Commitment to the nonce
+    pub r: JubJubExtended,
+}
+
+impl From<JubJubScalar> for SecretKey {
+    fn from(scalar: JubJubScalar) -> Self {
+        SecretKey(scalar)
+    }
+}
+
+impl SecretKey {
+    /// Generate a secret key
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        SecretKey(JubJubScalar::random(rng))
+    }
+
+    /// Public key of the secret key
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(GENERATOR_EXTENDED * self.0)
+    }
+
+    /// Nonce for the signature of a message, derived by reducing two outputs
+    /// of the sponge modulo the Jubjub order
+    fn nonce(&self, message: BlsScalar) -> JubJubScalar {
+        let output = sponge_zelbet_domain_out_of(
+            domain_tag(SCHNORR_NONCE_DOMAIN),
+            vec![to_bls_scalar(&self.0), message],
+            2,
+        );
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&output[0].to_bytes());
+        bytes[32..].copy_from_slice(&output[1].to_bytes());
+        JubJubScalar::from_bytes_wide(&bytes)
+    }
+
+    /// Sign a message
+    pub fn sign(&self, message: BlsScalar) -> Signature {
+        let public_key = JubJubAffine::from(self.public_key().0);
+        let nonce = self.nonce(message);
+        let r = GENERATOR_EXTENDED * nonce;
+        let c = challenge(&JubJubAffine::from(r), &public_key, message);
+
+        Signature {
+            u: nonce - c * self.0,
+            r,
+        }
+    }
+}
+
+impl PublicKey {
+    /// Verify the signature of a message
+    pub fn verify(&self, signature: &Signature, message: BlsScalar) -> bool {
+        let c = challenge(
+            &JubJubAffine::from(signature.r),
+            &JubJubAffine::from(self.0),
+            message,
+        );
+        GENERATOR_EXTENDED * signature.u + self.0 * c == signature.r
+    }
+}
+
+/// Verify a signature in circuit, where the public key and message are given
+/// as variables along with their values. The signature is added as a witness.
+#[allow(clippy::too_many_arguments)]
+pub fn schnorr_verification_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    public_key: Point,
+    public_key_value: &PublicKey,
+    message: Variable,
+    message_value: BlsScalar,
+    signature: &Signature,
+) {
+    let r_affine = JubJubAffine::from(signature.r);
+    let r = composer.add_affine(r_affine);
+    let u = composer.add_input(to_bls_scalar(&signature.u));
+
+    let public_key_affine = JubJubAffine::from(public_key_value.0);
+    let hash = sponge_zelbet_domain_gadget(
+        composer,
+        constants,
+        domain_tag(SCHNORR_DOMAIN),
+        vec![*r.x(), *r.y(), *public_key.x(), *public_key.y(), message],
+        &[
+            r_affine.get_x(),
+            r_affine.get_y(),
+            public_key_affine.get_x(),
+            public_key_affine.get_y(),
+            message_value,
+        ],
+        1,
+    )[0];
+
+    let hash_value =
+        challenge_hash(&r_affine, &public_key_affine, message_value);
+    let c = truncate_challenge_gadget(composer, hash, hash_value);
+
+    let u_g = composer.fixed_base_scalar_mul(u, GENERATOR_EXTENDED);
+    let c_pk = composer.variable_base_scalar_mul(c, public_key);
+    let lhs = composer.point_addition_gate(u_g, c_pk);
+    composer.assert_equal_point(lhs, r);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+    use rand_core::OsRng;
+
+    fn test_key() -> SecretKey {
+        SecretKey::from(JubJubScalar::from(0x1234567u64))
+    }
+
+    #[test]
+    fn test_schnorr_vectors() {
+        let public_key = JubJubAffine::from(test_key().public_key().0);
+        assert_eq!(
+            public_key.get_x(),
+            BlsScalar::from_raw([
+                0x0a743470aeebe8ef,
+                0xbe3c743e4eb5d549,
+                0xc647c47165a99ecc,
+                0x0cd6bbc4dedf246d,
+            ])
+        );
+        assert_eq!(
+            public_key.get_y(),
+            BlsScalar::from_raw([
+                0x283fd9f573451868,
+                0xbecbd4d59fd9f73a,
+                0x1e022af835b711bf,
+                0x1b7f1ff82b516f71,
+            ])
+        );
+
+        let signature = test_key().sign(BlsScalar::from(42));
+        assert_eq!(
+            signature.u,
+            JubJubScalar::from_raw([
+                0x8cee0d9364156dbf,
+                0xe34181e98a73d785,
+                0xd7e0858fdb03a1fa,
+                0x055e84e811467440,
+            ])
+        );
+        let r = JubJubAffine::from(signature.r);
+        assert_eq!(
+            r.get_x(),
+            BlsScalar::from_raw([
+                0x328342f1312c4da0,
+                0xb6b60f26949fa0fd,
+                0xf177760c68f6da70,
+                0x450ce2c3f3ef4018,
+            ])
+        );
+        assert_eq!(
+            r.get_y(),
+            BlsScalar::from_raw([
+                0x124fdcf95e89515c,
+                0xadb629a008809931,
+                0xe78a373012cc16cb,
+                0x02e0c4aac9ff2538,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_schnorr_sign_and_verify() {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let public_key = secret_key.public_key();
+        let message = BlsScalar::from(42);
+        let signature = secret_key.sign(message);
+        assert!(public_key.verify(&signature, message));
+        assert_eq!(signature, secret_key.sign(message));
+
+        assert!(!public_key.verify(&signature, BlsScalar::from(43)));
+        assert!(!SecretKey::random(&mut OsRng)
+            .public_key()
+            .verify(&signature, message));
+
+        let mut tampered = signature;
+        tampered.u += JubJubScalar::one();
+        assert!(!public_key.verify(&tampered, message));
+        let mut tampered = signature;
+        tampered.r += GENERATOR_EXTENDED;
+        assert!(!public_key.verify(&tampered, message));
+    }
+
+    #[test]
+    fn test_schnorr_verification_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let secret_key = test_key();
+                let public_key_value = secret_key.public_key();
+                let message_value = BlsScalar::from(42);
+                let signature = secret_key.sign(message_value);
+
+                let public_key_affine = JubJubAffine::from(public_key_value.0);
+                let public_key = composer.add_affine(public_key_affine);
+                composer
+                    .assert_equal_public_point(public_key, public_key_affine);
+                let message = composer.add_input(message_value);
+                composer.constrain_to_constant(
+                    message,
+                    BlsScalar::zero(),
+                    Some(-message_value),
+                );
+
+                schnorr_verification_gadget(
+                    composer,
+                    &constants,
+                    public_key,
+                    &public_key_value,
+                    message,
+                    message_value,
+                    &signature,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            17000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_schnorr_verification_gadget_wrong_message_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let secret_key = test_key();
+                let public_key_value = secret_key.public_key();
+                let signature = secret_key.sign(BlsScalar::from(42));
+
+                let public_key_affine = JubJubAffine::from(public_key_value.0);
+                let public_key = composer.add_affine(public_key_affine);
+                composer
+                    .assert_equal_public_point(public_key, public_key_affine);
+                let message = composer.add_input(BlsScalar::from(43));
+
+                schnorr_verification_gadget(
+                    composer,
+                    &constants,
+                    public_key,
+                    &public_key_value,
+                    message,
+                    BlsScalar::from(43),
+                    &signature,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            17000,
+        );
+        assert!(res.is_err());
+    }
+}
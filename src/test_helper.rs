@@ -69,6 +69,38 @@ use rand_core::{CryptoRng, OsRng};
 pub(crate) fn gadget_tester(
     gadget: fn(composer: &mut StandardComposer),
     n: usize,
+) -> Result<(), Error> {
+    end_to_end_tester(&gadget, &gadget, n)
+}
+
+/// Runs the end-to-end test of [`gadget_tester`] with the circuit of the
+/// prover built by `prover_gadget` and that of the verifier by
+/// `verifier_gadget`. Both must describe the same circuit and only differ in
+/// the values of the witnesses, so the test checks whether the witness of the
+/// prover satisfies the circuit of the verifier.
+#[cfg(test)]
+pub(crate) fn tamper_tester(
+    prover_gadget: &dyn Fn(&mut StandardComposer),
+    verifier_gadget: &dyn Fn(&mut StandardComposer),
+    n: usize,
+) -> Result<(), Error> {
+    let mut prover_composer = StandardComposer::new();
+    prover_gadget(&mut prover_composer);
+    let mut verifier_composer = StandardComposer::new();
+    verifier_gadget(&mut verifier_composer);
+    assert_eq!(
+        prover_composer.circuit_size(),
+        verifier_composer.circuit_size(),
+        "the prover and the verifier build the same circuit"
+    );
+
+    end_to_end_tester(prover_gadget, verifier_gadget, n)
+}
+
+fn end_to_end_tester(
+    prover_gadget: &dyn Fn(&mut StandardComposer),
+    verifier_gadget: &dyn Fn(&mut StandardComposer),
+    n: usize,
 ) -> Result<(), Error> {
     // Common View
     let public_parameters = PublicParameters::setup(2 * n, &mut OsRng)?;
@@ -81,7 +113,7 @@ pub(crate) fn gadget_tester(
         prover.key_transcript(b"key", b"additional seed information");
 
         // Add gadgets
-        gadget(&mut prover.mut_cs());
+        prover_gadget(&mut prover.mut_cs());
 
         // Commit Key
         let (ck, _) = public_parameters
@@ -107,7 +139,7 @@ pub(crate) fn gadget_tester(
     verifier.key_transcript(b"key", b"additional seed information");
 
     // Add gadgets
-    gadget(&mut verifier.mut_cs());
+    verifier_gadget(&mut verifier.mut_cs());
 
     // Compute Commit and Verifier Key
     let (ck, vk) = public_parameters
@@ -0,0 +1,380 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Fiat-Shamir transcript over BLS scalars, built on the Zelbet duplex so that
+//! the verifier of a protocol can itself be arithmetised.
+//!
+//! Every operation is encoded as a kind, the label of the operation and its
+//! payload, and queued. The queue is absorbed two elements at a time, with a
+//! permutation after each block, when a challenge is requested, and the
+//! challenges are then squeezed from the rate. Every encoded operation starts
+//! with a non-zero kind, so the zero padding of the last block can never make
+//! two different transcripts agree. The protocol label is appended as the
+//! first message of the transcript.
+//!
+//! [`TranscriptGadget`] performs the same operations in circuit, where the
+//! labels and messages are constants of the circuit and the scalars are
+//! variables given along with their values.
+
+use crate::hashing::sponge::{bytes_to_scalars, domain_tag};
+use crate::hashing::zelbet::{zelbet_out_of_circuit, ZelbetConstants};
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Label of the duplex domain used for transcripts
+pub const TRANSCRIPT_DOMAIN: &[u8] = b"rc.transcript";
+
+const KIND_MESSAGE: u64 = 1;
+const KIND_SCALARS: u64 = 2;
+const KIND_CHALLENGE: u64 = 3;
+
+/// Encoding of an operation with the given kind and label, without its payload
+fn operation_header(kind: u64, label: &[u8]) -> Vec<BlsScalar> {
+    let mut header = vec![BlsScalar::from(kind)];
+    header.extend(bytes_to_scalars(label));
+    header
+}
+
+/// Fiat-Shamir transcript (out of circuit)
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    state: [BlsScalar; 3],
+    pending: Vec<BlsScalar>,
+}
+
+impl Transcript {
+    /// Start a transcript for the protocol with the given label
+    pub fn new(protocol: &[u8]) -> Self {
+        let mut transcript = Transcript {
+            state: [
+                BlsScalar::zero(),
+                BlsScalar::zero(),
+                domain_tag(TRANSCRIPT_DOMAIN),
+            ],
+            pending: vec![],
+        };
+        transcript.append_message(b"dom-sep", protocol);
+        transcript
+    }
+
+    /// Append a labelled byte message
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        self.pending.extend(operation_header(KIND_MESSAGE, label));
+        self.pending.extend(bytes_to_scalars(message));
+    }
+
+    /// Append a labelled scalar
+    pub fn append_scalar(&mut self, label: &[u8], scalar: BlsScalar) {
+        self.append_scalars(label, &[scalar]);
+    }
+
+    /// Append a labelled vector of scalars
+    pub fn append_scalars(&mut self, label: &[u8], scalars: &[BlsScalar]) {
+        self.pending.extend(operation_header(KIND_SCALARS, label));
+        self.pending.push(BlsScalar::from(scalars.len() as u64));
+        self.pending.extend_from_slice(scalars);
+    }
+
+    /// Squeeze a labelled challenge
+    pub fn challenge_scalar(&mut self, label: &[u8]) -> BlsScalar {
+        self.challenge_scalars(label, 1)[0]
+    }
+
+    /// Squeeze a labelled vector of challenges
+    pub fn challenge_scalars(
+        &mut self,
+        label: &[u8],
+        n: usize,
+    ) -> Vec<BlsScalar> {
+        self.pending.extend(operation_header(KIND_CHALLENGE, label));
+        self.pending.push(BlsScalar::from(n as u64));
+
+        let pending = std::mem::take(&mut self.pending);
+        pending.chunks(2).for_each(|block| {
+            block
+                .iter()
+                .enumerate()
+                .for_each(|(i, element)| self.state[i] += element);
+            self.state = zelbet_out_of_circuit(self.state);
+        });
+
+        (0..n)
+            .map(|k| {
+                if k > 0 && k % 2 == 0 {
+                    self.state = zelbet_out_of_circuit(self.state);
+                }
+                self.state[k % 2]
+            })
+            .collect()
+    }
+}
+
+/// Fiat-Shamir transcript in circuit, mirroring [`Transcript`]. The native
+/// transcript follows the values of the variables.
+#[derive(Debug, Clone)]
+pub struct TranscriptGadget {
+    constants: ZelbetConstants,
+    state: [Variable; 3],
+    pending: Vec<Variable>,
+    values: Transcript,
+}
+
+impl TranscriptGadget {
+    /// Start a transcript for the protocol with the given label
+    pub fn new(
+        composer: &mut StandardComposer,
+        constants: &ZelbetConstants,
+        protocol: &[u8],
+    ) -> Self {
+        let domain = composer
+            .add_witness_to_circuit_description(domain_tag(TRANSCRIPT_DOMAIN));
+        let mut transcript = TranscriptGadget {
+            constants: *constants,
+            state: [constants.zero, constants.zero, domain],
+            pending: vec![],
+            values: Transcript {
+                state: [
+                    BlsScalar::zero(),
+                    BlsScalar::zero(),
+                    domain_tag(TRANSCRIPT_DOMAIN),
+                ],
+                pending: vec![],
+            },
+        };
+        transcript.append_message(composer, b"dom-sep", protocol);
+        transcript
+    }
+
+    fn append_constants(
+        &mut self,
+        composer: &mut StandardComposer,
+        elements: Vec<BlsScalar>,
+    ) {
+        elements.into_iter().for_each(|element| {
+            self.pending
+                .push(composer.add_witness_to_circuit_description(element));
+            self.values.pending.push(element);
+        });
+    }
+
+    /// Append a labelled byte message, which is a constant of the circuit
+    pub fn append_message(
+        &mut self,
+        composer: &mut StandardComposer,
+        label: &[u8],
+        message: &[u8],
+    ) {
+        self.append_constants(composer, operation_header(KIND_MESSAGE, label));
+        self.append_constants(composer, bytes_to_scalars(message));
+    }
+
+    /// Append a labelled scalar, given along with its value
+    pub fn append_scalar(
+        &mut self,
+        composer: &mut StandardComposer,
+        label: &[u8],
+        scalar: Variable,
+        value: BlsScalar,
+    ) {
+        self.append_scalars(composer, label, &[scalar], &[value]);
+    }
+
+    /// Append a labelled vector of scalars, given along with their values. The
+    /// length of the vector is part of the circuit description.
+    pub fn append_scalars(
+        &mut self,
+        composer: &mut StandardComposer,
+        label: &[u8],
+        scalars: &[Variable],
+        values: &[BlsScalar],
+    ) {
+        assert_eq!(scalars.len(), values.len());
+        let mut header = operation_header(KIND_SCALARS, label);
+        header.push(BlsScalar::from(scalars.len() as u64));
+        self.append_constants(composer, header);
+        self.pending.extend_from_slice(scalars);
+        self.values.pending.extend_from_slice(values);
+    }
+
+    /// Squeeze a labelled challenge
+    pub fn challenge_scalar(
+        &mut self,
+        composer: &mut StandardComposer,
+        label: &[u8],
+    ) -> Variable {
+        self.challenge_scalars(composer, label, 1)[0]
+    }
+
+    /// Squeeze a labelled vector of challenges
+    pub fn challenge_scalars(
+        &mut self,
+        composer: &mut StandardComposer,
+        label: &[u8],
+        n: usize,
+    ) -> Vec<Variable> {
+        let mut header = operation_header(KIND_CHALLENGE, label);
+        header.push(BlsScalar::from(n as u64));
+        self.append_constants(composer, header);
+
+        let pending = std::mem::take(&mut self.pending);
+        let pending_values = std::mem::take(&mut self.values.pending);
+        pending.chunks(2).zip(pending_values.chunks(2)).for_each(
+            |(block, block_values)| {
+                block.iter().zip(block_values.iter()).enumerate().for_each(
+                    |(i, (element, value))| {
+                        self.state[i] = composer.big_add(
+                            (BlsScalar::one(), self.state[i]),
+                            (BlsScalar::one(), *element),
+                            None,
+                            BlsScalar::zero(),
+                            None,
+                        );
+                        self.values.state[i] += value;
+                    },
+                );
+                self.permute(composer);
+            },
+        );
+
+        (0..n)
+            .map(|k| {
+                if k > 0 && k % 2 == 0 {
+                    self.permute(composer);
+                }
+                self.state[k % 2]
+            })
+            .collect()
+    }
+
+    fn permute(&mut self, composer: &mut StandardComposer) {
+        self.state =
+            self.constants
+                .permute(composer, &self.state, &self.values.state);
+        self.values.state = zelbet_out_of_circuit(self.values.state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+
+    #[test]
+    fn test_transcript_vectors() {
+        let mut transcript = Transcript::new(b"test protocol");
+        transcript
+            .append_scalars(b"x", &[BlsScalar::from(5), -BlsScalar::one()]);
+        assert_eq!(
+            transcript.challenge_scalar(b"c"),
+            BlsScalar([
+                10184775360781694282,
+                8068319155547205776,
+                11660132691652939617,
+                6802066250445573341,
+            ])
+        );
+
+        transcript.append_message(b"m", b"hello");
+        assert_eq!(
+            transcript.challenge_scalars(b"d", 3),
+            vec![
+                BlsScalar([
+                    15234465647683703072,
+                    5745576324030048788,
+                    1169586855081668384,
+                    4943175056094311888,
+                ]),
+                BlsScalar([
+                    1503733917484450837,
+                    2272296109985941591,
+                    9173527218226787792,
+                    5210793950946833711,
+                ]),
+                BlsScalar([
+                    13801352981783370784,
+                    3687876700940447361,
+                    2957507986110203171,
+                    939291900229064316,
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transcript_separation() {
+        let challenge =
+            |protocol: &[u8], label: &[u8], scalars: &[BlsScalar]| {
+                let mut transcript = Transcript::new(protocol);
+                transcript.append_scalars(label, scalars);
+                transcript.challenge_scalar(b"c")
+            };
+        let scalars = [BlsScalar::from(5), BlsScalar::from(6)];
+        let reference = challenge(b"protocol", b"x", &scalars);
+
+        assert_eq!(reference, challenge(b"protocol", b"x", &scalars));
+        assert_ne!(reference, challenge(b"other protocol", b"x", &scalars));
+        assert_ne!(reference, challenge(b"protocol", b"y", &scalars));
+        assert_ne!(reference, challenge(b"protocol", b"x", &scalars[..1]));
+        assert_ne!(
+            reference,
+            challenge(b"protocol", b"x", &[scalars[1], scalars[0]])
+        );
+
+        // Squeezing a challenge changes every later challenge
+        let mut transcript = Transcript::new(b"protocol");
+        let first = transcript.challenge_scalar(b"c");
+        assert_ne!(first, transcript.challenge_scalar(b"c"));
+
+        // The same bytes as a message and as a label are not confused
+        let mut transcript = Transcript::new(b"protocol");
+        transcript.append_message(b"ab", b"");
+        let mut other = Transcript::new(b"protocol");
+        other.append_message(b"", b"ab");
+        assert_ne!(
+            transcript.challenge_scalar(b"c"),
+            other.challenge_scalar(b"c")
+        );
+    }
+
+    #[test]
+    fn test_transcript_gadget_matches_native() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let scalars = [BlsScalar::from(5), -BlsScalar::one()];
+                let mut transcript = Transcript::new(b"test protocol");
+                transcript.append_scalars(b"x", &scalars);
+                transcript.append_message(b"m", b"hello");
+                let challenges = transcript.challenge_scalars(b"d", 3);
+
+                let variables: Vec<Variable> =
+                    scalars.iter().map(|s| composer.add_input(*s)).collect();
+                let mut gadget = TranscriptGadget::new(
+                    composer,
+                    &constants,
+                    b"test protocol",
+                );
+                gadget.append_scalars(composer, b"x", &variables, &scalars);
+                gadget.append_message(composer, b"m", b"hello");
+                let computed = gadget.challenge_scalars(composer, b"d", 3);
+
+                computed.iter().zip(challenges.iter()).for_each(|(c, e)| {
+                    composer.constrain_to_constant(*c, *e, None);
+                });
+
+                pad_lookup_gates(composer);
+            },
+            10000,
+        );
+        assert!(res.is_ok());
+    }
+}
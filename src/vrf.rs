@@ -0,0 +1,435 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Verifiable random function in the style of ECVRF, on the Jubjub curve with
+//! the Zelbet sponge as hash, using the keys of [`crate::schnorr`].
+//!
+//! The input `alpha` is hashed to a point `H` with [`hash_to_curve`], and the
+//! proof is `(gamma, c, s)` where `gamma = sk·H` and `(c, s)` is a proof of
+//! equality of the discrete logarithms of `PK` and `gamma`: with the nonce
+//! `k`, `U = k·G`, `V = k·H`, the challenge `c` is the hash of
+//! `[PK, H, gamma, U, V]` truncated as for signatures, and `s = k - c·sk`. The
+//! verifier recomputes `U = s·G + c·PK` and `V = s·H + c·gamma` and checks the
+//! challenge. The output `beta` is the hash of `8·gamma`, so that it does not
+//! depend on a small order component that a prover could add to `gamma`.
+
+use crate::hash_to_curve::hash_to_curve;
+use crate::hashing::sponge::{
+    domain_tag, sponge_zelbet_domain_gadget, sponge_zelbet_domain_out_of,
+};
+use crate::hashing::zelbet::ZelbetConstants;
+use crate::schnorr::{
+    to_bls_scalar, truncate_challenge, truncate_challenge_gadget, PublicKey,
+    SecretKey,
+};
+use dusk_bytes::Serializable;
+use dusk_jubjub::{
+    JubJubAffine, JubJubExtended, JubJubScalar, EDWARDS_D, GENERATOR_EXTENDED,
+};
+use dusk_plonk::{
+    constraint_system::{ecc::Point, StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Domain separation tag used to hash inputs to the curve
+pub const VRF_HASH_TO_CURVE_DST: &[u8] = b"rc.vrf.h2c";
+
+/// Label of the sponge domain used for challenges
+pub const VRF_DOMAIN: &[u8] = b"rc.vrf";
+
+/// Label of the sponge domain used for the derivation of nonces
+pub const VRF_NONCE_DOMAIN: &[u8] = b"rc.vrf.nonce";
+
+/// Label of the sponge domain used for outputs
+pub const VRF_OUTPUT_DOMAIN: &[u8] = b"rc.vrf.output";
+
+/// Proof `(gamma, c, s)` of the output of the VRF on an input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VrfProof {
+    /// Product of the secret key and the hash of the input
+    pub gamma: JubJubExtended,
+    /// Challenge of the proof of equality of discrete logarithms
+    pub c: JubJubScalar,
+    /// Response of the proof of equality of discrete logarithms
+    pub s: JubJubScalar,
+}
+
+/// Point that the input is hashed to
+pub fn vrf_input_point(alpha: &[u8]) -> JubJubAffine {
+    JubJubAffine::from(hash_to_curve(VRF_HASH_TO_CURVE_DST, alpha))
+}
+
+/// Full hash from which the challenge of a proof is truncated
+fn challenge_hash(points: [&JubJubAffine; 5]) -> BlsScalar {
+    let input = points
+        .iter()
+        .flat_map(|point| vec![point.get_x(), point.get_y()])
+        .collect();
+    sponge_zelbet_domain_out_of(domain_tag(VRF_DOMAIN), input, 1)[0]
+}
+
+fn nonce(secret_key: &SecretKey, h: &JubJubAffine) -> JubJubScalar {
+    let output = sponge_zelbet_domain_out_of(
+        domain_tag(VRF_NONCE_DOMAIN),
+        vec![to_bls_scalar(&secret_key.0), h.get_x(), h.get_y()],
+        2,
+    );
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&output[0].to_bytes());
+    bytes[32..].copy_from_slice(&output[1].to_bytes());
+    JubJubScalar::from_bytes_wide(&bytes)
+}
+
+/// Output of the VRF for a proof, without checking the proof
+pub fn proof_to_hash(proof: &VrfProof) -> BlsScalar {
+    let gamma = JubJubAffine::from(proof.gamma.mul_by_cofactor());
+    sponge_zelbet_domain_out_of(
+        domain_tag(VRF_OUTPUT_DOMAIN),
+        vec![gamma.get_x(), gamma.get_y()],
+        1,
+    )[0]
+}
+
+/// Evaluate the VRF on an input, returning the output and its proof
+pub fn prove(secret_key: &SecretKey, alpha: &[u8]) -> (BlsScalar, VrfProof) {
+    let public_key = JubJubAffine::from(secret_key.public_key().0);
+    let h = vrf_input_point(alpha);
+    let gamma = JubJubExtended::from(h) * secret_key.0;
+
+    let k = nonce(secret_key, &h);
+    let u = JubJubAffine::from(GENERATOR_EXTENDED * k);
+    let v = JubJubAffine::from(JubJubExtended::from(h) * k);
+    let c = truncate_challenge(challenge_hash([
+        &public_key,
+        &h,
+        &JubJubAffine::from(gamma),
+        &u,
+        &v,
+    ]));
+
+    let proof = VrfProof {
+        gamma,
+        c,
+        s: k - c * secret_key.0,
+    };
+    (proof_to_hash(&proof), proof)
+}
+
+/// Verify the proof of the output of the VRF on an input, returning the
+/// output when the proof is valid
+pub fn verify(
+    public_key: &PublicKey,
+    alpha: &[u8],
+    proof: &VrfProof,
+) -> Option<BlsScalar> {
+    let h = vrf_input_point(alpha);
+    let u = GENERATOR_EXTENDED * proof.s + public_key.0 * proof.c;
+    let v = JubJubExtended::from(h) * proof.s + proof.gamma * proof.c;
+    let c = truncate_challenge(challenge_hash([
+        &JubJubAffine::from(public_key.0),
+        &h,
+        &JubJubAffine::from(proof.gamma),
+        &JubJubAffine::from(u),
+        &JubJubAffine::from(v),
+    ]));
+
+    match c == proof.c {
+        true => Some(proof_to_hash(proof)),
+        false => None,
+    }
+}
+
+/// Constrain a point to lie on the curve `-x^2 + y^2 = 1 + d·x^2·y^2`
+fn assert_on_curve_gadget(composer: &mut StandardComposer, point: Point) {
+    let x_squared = composer.mul(
+        BlsScalar::one(),
+        *point.x(),
+        *point.x(),
+        BlsScalar::zero(),
+        None,
+    );
+    let y_squared = composer.mul(
+        BlsScalar::one(),
+        *point.y(),
+        *point.y(),
+        BlsScalar::zero(),
+        None,
+    );
+    let product = composer.mul(
+        BlsScalar::one(),
+        x_squared,
+        y_squared,
+        BlsScalar::zero(),
+        None,
+    );
+    let equation = composer.big_add(
+        (-BlsScalar::one(), x_squared),
+        (BlsScalar::one(), y_squared),
+        Some((-EDWARDS_D, product)),
+        -BlsScalar::one(),
+        None,
+    );
+    composer.constrain_to_constant(equation, BlsScalar::zero(), None);
+}
+
+/// Verify the proof of the output of the VRF in circuit, returning the
+/// output. The public key and the hash `H` of the input are given as points
+/// along with their values, so that `H` can either be computed from a public
+/// input outside of the circuit, or inside it with
+/// [`crate::hash_to_curve::hash_to_curve_gadget`] and
+/// [`VRF_HASH_TO_CURVE_DST`] to keep the input private. The proof is added as
+/// a witness.
+pub fn vrf_verification_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    public_key: Point,
+    public_key_value: &PublicKey,
+    h: Point,
+    h_value: &JubJubAffine,
+    proof: &VrfProof,
+) -> Variable {
+    let gamma_affine = JubJubAffine::from(proof.gamma);
+    let gamma = composer.add_affine(gamma_affine);
+    assert_on_curve_gadget(composer, gamma);
+    let c = composer.add_input(to_bls_scalar(&proof.c));
+    let s = composer.add_input(to_bls_scalar(&proof.s));
+
+    let s_g = composer.fixed_base_scalar_mul(s, GENERATOR_EXTENDED);
+    let c_pk = composer.variable_base_scalar_mul(c, public_key);
+    let u = composer.point_addition_gate(s_g, c_pk);
+    let s_h = composer.variable_base_scalar_mul(s, h);
+    let c_gamma = composer.variable_base_scalar_mul(c, gamma);
+    let v = composer.point_addition_gate(s_h, c_gamma);
+
+    let u_value = GENERATOR_EXTENDED * proof.s + public_key_value.0 * proof.c;
+    let v_value =
+        JubJubExtended::from(*h_value) * proof.s + proof.gamma * proof.c;
+    let points = [
+        &JubJubAffine::from(public_key_value.0),
+        h_value,
+        &gamma_affine,
+        &JubJubAffine::from(u_value),
+        &JubJubAffine::from(v_value),
+    ];
+    let hash_value = challenge_hash(points);
+    let point_values: Vec<BlsScalar> = points
+        .iter()
+        .flat_map(|point| vec![point.get_x(), point.get_y()])
+        .collect();
+
+    let hash = sponge_zelbet_domain_gadget(
+        composer,
+        constants,
+        domain_tag(VRF_DOMAIN),
+        vec![
+            *public_key.x(),
+            *public_key.y(),
+            *h.x(),
+            *h.y(),
+            *gamma.x(),
+            *gamma.y(),
+            *u.x(),
+            *u.y(),
+            *v.x(),
+            *v.y(),
+        ],
+        &point_values,
+        1,
+    )[0];
+    let computed = truncate_challenge_gadget(composer, hash, hash_value);
+    composer.assert_equal(computed, c);
+
+    // Output from the cofactor multiple of gamma
+    let gamma_8 = (0..3)
+        .fold(gamma, |point, _| composer.point_addition_gate(point, point));
+    let gamma_8_value = JubJubAffine::from(proof.gamma.mul_by_cofactor());
+    sponge_zelbet_domain_gadget(
+        composer,
+        constants,
+        domain_tag(VRF_OUTPUT_DOMAIN),
+        vec![*gamma_8.x(), *gamma_8.y()],
+        &[gamma_8_value.get_x(), gamma_8_value.get_y()],
+        1,
+    )[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+    use rand_core::OsRng;
+
+    fn test_key() -> SecretKey {
+        SecretKey::from(JubJubScalar::from(0x1234567u64))
+    }
+
+    #[test]
+    fn test_vrf_vectors() {
+        let (beta, proof) = prove(&test_key(), b"round 1");
+        assert_eq!(
+            vrf_input_point(b"round 1").get_x(),
+            BlsScalar::from_raw([
+                0xc7969719a762199c,
+                0x9b0d5a0498ae3322,
+                0x2ac0fc36efbc782e,
+                0x61cad457eaa78295,
+            ])
+        );
+        let gamma = JubJubAffine::from(proof.gamma);
+        assert_eq!(
+            gamma.get_x(),
+            BlsScalar::from_raw([
+                0x09e15c3211119f5c,
+                0x18e56d62d0a8278e,
+                0x40a0175e278e589b,
+                0x547a497238fb5796,
+            ])
+        );
+        assert_eq!(
+            gamma.get_y(),
+            BlsScalar::from_raw([
+                0x0fa81acbd509cc53,
+                0x8137f429810abcd1,
+                0x9b3e616b2a272a24,
+                0x176370f05dd58ea7,
+            ])
+        );
+        assert_eq!(
+            proof.c,
+            JubJubScalar::from_raw([
+                0x0ece8b7681dd671e,
+                0xc38c92c5e7cff2f7,
+                0x8e58d9c19e819c23,
+                0x029df849cdd663eb,
+            ])
+        );
+        assert_eq!(
+            proof.s,
+            JubJubScalar::from_raw([
+                0x8b4c0971bfffd298,
+                0x4f92622bd1191983,
+                0x553473616e851ed1,
+                0x066a733d524e25aa,
+            ])
+        );
+        assert_eq!(
+            beta,
+            BlsScalar([
+                18016977007779380705,
+                17399268343078369499,
+                8298655525889650392,
+                5065749988489202074,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_vrf_prove_and_verify() {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let public_key = secret_key.public_key();
+        let (beta, proof) = prove(&secret_key, b"round 1");
+        assert_eq!(verify(&public_key, b"round 1", &proof), Some(beta));
+        assert_eq!(prove(&secret_key, b"round 1"), (beta, proof));
+        assert_ne!(prove(&secret_key, b"round 2").0, beta);
+
+        assert_eq!(verify(&public_key, b"round 2", &proof), None);
+        assert_eq!(
+            verify(
+                &SecretKey::random(&mut OsRng).public_key(),
+                b"round 1",
+                &proof
+            ),
+            None
+        );
+
+        let mut tampered = proof;
+        tampered.gamma += GENERATOR_EXTENDED;
+        assert_eq!(verify(&public_key, b"round 1", &tampered), None);
+        let mut tampered = proof;
+        tampered.s += JubJubScalar::one();
+        assert_eq!(verify(&public_key, b"round 1", &tampered), None);
+    }
+
+    #[test]
+    fn test_vrf_verification_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let secret_key = test_key();
+                let public_key_value = secret_key.public_key();
+                let (beta, proof) = prove(&secret_key, b"round 1");
+
+                let public_key_affine = JubJubAffine::from(public_key_value.0);
+                let public_key = composer.add_affine(public_key_affine);
+                composer
+                    .assert_equal_public_point(public_key, public_key_affine);
+                let h_value = vrf_input_point(b"round 1");
+                let h = composer.add_affine(h_value);
+                composer.assert_equal_public_point(h, h_value);
+
+                let output = vrf_verification_gadget(
+                    composer,
+                    &constants,
+                    public_key,
+                    &public_key_value,
+                    h,
+                    &h_value,
+                    &proof,
+                );
+                composer.constrain_to_constant(
+                    output,
+                    BlsScalar::zero(),
+                    Some(-beta),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            33000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_vrf_verification_gadget_wrong_input_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let secret_key = test_key();
+                let public_key_value = secret_key.public_key();
+                let (_, proof) = prove(&secret_key, b"round 1");
+
+                let public_key_affine = JubJubAffine::from(public_key_value.0);
+                let public_key = composer.add_affine(public_key_affine);
+                composer
+                    .assert_equal_public_point(public_key, public_key_affine);
+                let h_value = vrf_input_point(b"round 2");
+                let h = composer.add_affine(h_value);
+                composer.assert_equal_public_point(h, h_value);
+
+                vrf_verification_gadget(
+                    composer,
+                    &constants,
+                    public_key,
+                    &public_key_value,
+                    h,
+                    &h_value,
+                    &proof,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            33000,
+        );
+        assert!(res.is_err());
+    }
+}
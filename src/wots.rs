@@ -0,0 +1,623 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Winternitz one-time signatures over BLS scalar messages, with hash chains
+//! built from the Zelbet permutation.
+//!
+//! The message is split into base `w` digits, followed by the digits of the
+//! checksum `sum(w - 1 - d_i)`, and every digit has its own chain. A step of
+//! chain `i` at position `j` maps `x` to the first element of
+//! `zelbet([x, tweak(i, j), seed])`, where the public seed sits in the
+//! capacity and the tweak is the domain tag of `"rc.wots"` followed by the
+//! chain index on two bytes and the position on one byte. The secret key of a
+//! chain is its start, the public key its end after `w - 1` steps, and the
+//! signature of digit `d` its value after `d` steps.
+//!
+//! A key must sign a single message: the signatures of two messages reveal
+//! enough of the chains to forge others.
+
+use crate::hashing::gadgets::scalar_bits_gadget;
+use crate::hashing::sponge::{
+    domain_tag, sponge_zelbet_domain_gadget, sponge_zelbet_domain_out_of,
+};
+use crate::hashing::zelbet::{zelbet_out_of_circuit, ZelbetConstants};
+use crate::prf::prf;
+use bigint::U256 as u256;
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Label of the tweaks of the chain steps
+pub const WOTS_DOMAIN: &[u8] = b"rc.wots";
+
+/// Label of the sponge domain used to compress public keys
+pub const WOTS_PUBLIC_KEY_DOMAIN: &[u8] = b"rc.wots.pk";
+
+/// Parameters of the scheme. Verifying a signature in circuit costs
+/// `num_chains() * (w() - 1)` permutations whatever the message, so circuits
+/// may prefer a smaller `w` than the default of 16, at the price of longer
+/// signatures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WotsParameters {
+    /// Base two logarithm of the base `w` of the digits, at most 8
+    pub log_w: usize,
+    /// Number of bits of the messages, at most 255
+    pub message_bits: usize,
+}
+
+impl Default for WotsParameters {
+    fn default() -> Self {
+        WotsParameters {
+            log_w: 4,
+            message_bits: 255,
+        }
+    }
+}
+
+impl WotsParameters {
+    /// Base of the digits, which is also the length of the chains
+    pub fn w(&self) -> usize {
+        1 << self.log_w
+    }
+
+    /// Number of digits of the message
+    pub fn message_digits(&self) -> usize {
+        (self.message_bits + self.log_w - 1) / self.log_w
+    }
+
+    /// Number of digits of the checksum
+    pub fn checksum_digits(&self) -> usize {
+        let max_checksum = self.message_digits() * (self.w() - 1);
+        (63 - (max_checksum as u64).leading_zeros() as usize) / self.log_w + 1
+    }
+
+    /// Number of chains, which is the length of keys and signatures
+    pub fn num_chains(&self) -> usize {
+        self.message_digits() + self.checksum_digits()
+    }
+
+    fn check(&self) {
+        assert!(self.log_w >= 1 && self.log_w <= 8, "w is 2 to 256");
+        assert!(self.message_bits <= 255, "messages are BLS scalars");
+    }
+
+    /// Digits of the message followed by those of the checksum, starting from
+    /// the least significant digit. The message must fit in `message_bits`
+    /// bits.
+    pub fn digits(&self, message: BlsScalar) -> Vec<usize> {
+        self.checked_digits(message).expect("message is too long")
+    }
+
+    /// Digits of the message and checksum as in [`digits`](Self::digits), or
+    /// `None` when the message does not fit in `message_bits` bits
+    pub fn checked_digits(&self, message: BlsScalar) -> Option<Vec<usize>> {
+        self.check();
+        let value = u256(message.reduce().0);
+        if value.bits() > self.message_bits {
+            return None;
+        }
+
+        let mask = self.w() as u64 - 1;
+        let mut digits: Vec<usize> = (0..self.message_digits())
+            .map(|k| ((value >> (k * self.log_w)).low_u64() & mask) as usize)
+            .collect();
+        let checksum: usize = digits.iter().map(|d| self.w() - 1 - d).sum();
+        (0..self.checksum_digits()).for_each(|k| {
+            digits.push((checksum >> (k * self.log_w)) & mask as usize)
+        });
+        Some(digits)
+    }
+}
+
+/// Tweak of the step of the given chain at the given position
+fn chain_tweak(chain: usize, position: usize) -> BlsScalar {
+    let mut label = WOTS_DOMAIN.to_vec();
+    label.extend_from_slice(&(chain as u16).to_le_bytes());
+    label.push(position as u8);
+    domain_tag(&label)
+}
+
+/// Difference between the tweaks of two consecutive positions, 2^72
+fn position_shift() -> BlsScalar {
+    BlsScalar::from(1u64 << 36).square()
+}
+
+/// Iterate the given chain for a number of steps from a starting position
+pub fn wots_chain(
+    x: BlsScalar,
+    chain: usize,
+    start: usize,
+    steps: usize,
+    seed: BlsScalar,
+) -> BlsScalar {
+    (start..start + steps).fold(x, |x, position| {
+        zelbet_out_of_circuit([x, chain_tweak(chain, position), seed])[0]
+    })
+}
+
+/// Secret key with the given index derived from a secret seed, with the PRF
+/// evaluated on `[index, chain]`
+pub fn wots_secret_key(
+    params: &WotsParameters,
+    secret_seed: BlsScalar,
+    index: u64,
+) -> Vec<BlsScalar> {
+    (0..params.num_chains())
+        .map(|chain| {
+            prf(
+                secret_seed,
+                &[BlsScalar::from(index), BlsScalar::from(chain as u64)],
+            )
+        })
+        .collect()
+}
+
+/// Public key of a secret key
+pub fn wots_public_key(
+    params: &WotsParameters,
+    secret_key: &[BlsScalar],
+    seed: BlsScalar,
+) -> Vec<BlsScalar> {
+    assert_eq!(secret_key.len(), params.num_chains());
+    secret_key
+        .iter()
+        .enumerate()
+        .map(|(chain, x)| wots_chain(*x, chain, 0, params.w() - 1, seed))
+        .collect()
+}
+
+/// Sign a message, which must fit in `message_bits` bits
+pub fn wots_sign(
+    params: &WotsParameters,
+    secret_key: &[BlsScalar],
+    seed: BlsScalar,
+    message: BlsScalar,
+) -> Vec<BlsScalar> {
+    assert_eq!(secret_key.len(), params.num_chains());
+    secret_key
+        .iter()
+        .zip(params.digits(message))
+        .enumerate()
+        .map(|(chain, (x, digit))| wots_chain(*x, chain, 0, digit, seed))
+        .collect()
+}
+
+/// Public key that a signature of the message verifies against, or `None`
+/// when the signature does not have one element per chain or the message does
+/// not fit in `message_bits` bits
+pub fn wots_public_key_from_signature(
+    params: &WotsParameters,
+    signature: &[BlsScalar],
+    seed: BlsScalar,
+    message: BlsScalar,
+) -> Option<Vec<BlsScalar>> {
+    if signature.len() != params.num_chains() {
+        return None;
+    }
+    let digits = params.checked_digits(message)?;
+    Some(
+        signature
+            .iter()
+            .zip(digits)
+            .enumerate()
+            .map(|(chain, (x, digit))| {
+                wots_chain(*x, chain, digit, params.w() - 1 - digit, seed)
+            })
+            .collect(),
+    )
+}
+
+/// Verify the signature of a message
+pub fn wots_verify(
+    params: &WotsParameters,
+    public_key: &[BlsScalar],
+    signature: &[BlsScalar],
+    seed: BlsScalar,
+    message: BlsScalar,
+) -> bool {
+    wots_public_key_from_signature(params, signature, seed, message).as_deref()
+        == Some(public_key)
+}
+
+/// Compress a public key into a single element
+pub fn wots_leaf(public_key: &[BlsScalar]) -> BlsScalar {
+    sponge_zelbet_domain_out_of(
+        domain_tag(WOTS_PUBLIC_KEY_DOMAIN),
+        public_key.to_vec(),
+        1,
+    )[0]
+}
+
+/// Compress a public key into a single element in circuit, where the public
+/// key is given as variables along with their values
+pub fn wots_leaf_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    public_key: &[Variable],
+    public_key_scalars: &[BlsScalar],
+) -> Variable {
+    sponge_zelbet_domain_gadget(
+        composer,
+        constants,
+        domain_tag(WOTS_PUBLIC_KEY_DOMAIN),
+        public_key.to_vec(),
+        public_key_scalars,
+        1,
+    )[0]
+}
+
+/// Compose little endian bits into an integer in circuit
+fn compose_bits(
+    composer: &mut StandardComposer,
+    zero: Variable,
+    bits: &[Variable],
+) -> Variable {
+    bits.iter().rev().fold(zero, |acc, bit| {
+        composer.big_add(
+            (BlsScalar::from(2), acc),
+            (BlsScalar::one(), *bit),
+            None,
+            BlsScalar::zero(),
+            None,
+        )
+    })
+}
+
+/// Compute in circuit the public key that a signature of the message verifies
+/// against. The seed, the message and the signature are given as variables
+/// along with their values.
+///
+/// Every chain is walked for `w - 1` steps, where step `k` is only applied
+/// when `k < w - 1 - d` for the digit `d` of the chain. These conditions are
+/// witnessed as bits `s_k`, constrained to be boolean, non-increasing and to
+/// sum to `w - 1 - d`, which leaves a single choice.
+#[allow(clippy::too_many_arguments)]
+pub fn wots_public_key_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    params: &WotsParameters,
+    seed: Variable,
+    seed_scalar: BlsScalar,
+    message: Variable,
+    message_scalar: BlsScalar,
+    signature: &[Variable],
+    signature_scalars: &[BlsScalar],
+) -> Vec<Variable> {
+    assert_eq!(signature.len(), params.num_chains());
+    assert_eq!(signature_scalars.len(), params.num_chains());
+    let digit_values = params.digits(message_scalar);
+    let w = params.w();
+    let zero = constants.zero;
+
+    // Digits of the message, from its bits padded to a whole number of digits
+    let mut message_bits = scalar_bits_gadget(
+        composer,
+        message,
+        message_scalar,
+        params.message_bits,
+    );
+    message_bits.resize(params.message_digits() * params.log_w, zero);
+    let mut digits: Vec<Variable> = message_bits
+        .chunks(params.log_w)
+        .map(|bits| compose_bits(composer, zero, bits))
+        .collect();
+
+    // Digits of the checksum
+    let checksum = digits.iter().fold(zero, |acc, digit| {
+        composer.big_add(
+            (BlsScalar::one(), acc),
+            (-BlsScalar::one(), *digit),
+            None,
+            BlsScalar::from((w - 1) as u64),
+            None,
+        )
+    });
+    let checksum_value: usize = digit_values[..params.message_digits()]
+        .iter()
+        .map(|d| w - 1 - d)
+        .sum();
+    let checksum_bits = scalar_bits_gadget(
+        composer,
+        checksum,
+        BlsScalar::from(checksum_value as u64),
+        params.checksum_digits() * params.log_w,
+    );
+    checksum_bits
+        .chunks(params.log_w)
+        .for_each(|bits| digits.push(compose_bits(composer, zero, bits)));
+
+    signature
+        .iter()
+        .zip(signature_scalars.iter())
+        .zip(digits.iter().zip(digit_values.iter()))
+        .enumerate()
+        .map(|(chain, ((x, x_scalar), (digit, digit_value)))| {
+            let steps: Vec<Variable> = (0..w - 1)
+                .map(|k| {
+                    let step = composer.add_input(BlsScalar::from(
+                        (k < w - 1 - digit_value) as u64,
+                    ));
+                    composer.boolean_gate(step);
+                    step
+                })
+                .collect();
+
+            // Once a step is skipped, so are all the following ones
+            steps.windows(2).for_each(|pair| {
+                let increase = composer.big_mul(
+                    -BlsScalar::one(),
+                    pair[1],
+                    pair[0],
+                    Some((BlsScalar::one(), pair[1])),
+                    BlsScalar::zero(),
+                    None,
+                );
+                composer.constrain_to_constant(
+                    increase,
+                    BlsScalar::zero(),
+                    None,
+                );
+            });
+
+            // The digit and the number of steps sum to w - 1
+            let total = steps.iter().fold(*digit, |acc, step| {
+                composer.big_add(
+                    (BlsScalar::one(), acc),
+                    (BlsScalar::one(), *step),
+                    None,
+                    BlsScalar::zero(),
+                    None,
+                )
+            });
+            composer.constrain_to_constant(
+                total,
+                BlsScalar::from((w - 1) as u64),
+                None,
+            );
+
+            let (key, _) = steps.iter().enumerate().fold(
+                (*x, *x_scalar),
+                |(node, node_scalar), (k, step)| {
+                    // The position of step k is d + k
+                    let tweak = composer.big_add(
+                        (position_shift(), *digit),
+                        (BlsScalar::zero(), zero),
+                        None,
+                        chain_tweak(chain, k),
+                        None,
+                    );
+                    let tweak_scalar = position_shift()
+                        * BlsScalar::from(*digit_value as u64)
+                        + chain_tweak(chain, k);
+                    let state_scalars =
+                        [node_scalar, tweak_scalar, seed_scalar];
+                    let next = constants.permute(
+                        composer,
+                        &[node, tweak, seed],
+                        &state_scalars,
+                    )[0];
+                    let difference = composer.big_add(
+                        (BlsScalar::one(), next),
+                        (-BlsScalar::one(), node),
+                        None,
+                        BlsScalar::zero(),
+                        None,
+                    );
+                    let node = composer.big_mul(
+                        BlsScalar::one(),
+                        *step,
+                        difference,
+                        Some((BlsScalar::one(), node)),
+                        BlsScalar::zero(),
+                        None,
+                    );
+                    let node_scalar = match k < w - 1 - digit_value {
+                        true => zelbet_out_of_circuit(state_scalars)[0],
+                        false => node_scalar,
+                    };
+                    (node, node_scalar)
+                },
+            );
+            key
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+
+    const PARAMS: WotsParameters = WotsParameters {
+        log_w: 2,
+        message_bits: 8,
+    };
+
+    #[test]
+    fn test_wots_parameters() {
+        let params = WotsParameters::default();
+        assert_eq!(
+            (
+                params.message_digits(),
+                params.checksum_digits(),
+                params.num_chains()
+            ),
+            (64, 3, 67)
+        );
+        assert_eq!(
+            (
+                PARAMS.message_digits(),
+                PARAMS.checksum_digits(),
+                PARAMS.num_chains()
+            ),
+            (4, 2, 6)
+        );
+
+        // 0xa7 has base 4 digits 3, 1, 2, 2 with checksum 4 = 0 + 1 * 4
+        assert_eq!(
+            PARAMS.digits(BlsScalar::from(0xa7)),
+            vec![3, 1, 2, 2, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_wots_vectors() {
+        let secret_key = wots_secret_key(&PARAMS, BlsScalar::from(1), 0);
+        let public_key =
+            wots_public_key(&PARAMS, &secret_key, BlsScalar::from(2));
+        assert_eq!(
+            wots_leaf(&public_key),
+            BlsScalar([
+                16223745156518496956,
+                1479787090041378976,
+                11077213658165347813,
+                5937790032274631376,
+            ])
+        );
+        let signature = wots_sign(
+            &PARAMS,
+            &secret_key,
+            BlsScalar::from(2),
+            BlsScalar::from(0xa7),
+        );
+        assert_eq!(
+            signature[0],
+            BlsScalar([
+                17340194866806540223,
+                2489459751594091336,
+                6133298667402585370,
+                1781653399601639300,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_wots_sign_and_verify() {
+        let seed = BlsScalar::from(2);
+        let secret_key = wots_secret_key(&PARAMS, BlsScalar::from(1), 0);
+        let public_key = wots_public_key(&PARAMS, &secret_key, seed);
+        let message = BlsScalar::from(0xa7);
+        let signature = wots_sign(&PARAMS, &secret_key, seed, message);
+
+        assert!(wots_verify(&PARAMS, &public_key, &signature, seed, message));
+        assert!(!wots_verify(
+            &PARAMS,
+            &public_key,
+            &signature,
+            seed,
+            BlsScalar::from(0xa6)
+        ));
+        assert!(!wots_verify(
+            &PARAMS,
+            &public_key,
+            &signature,
+            BlsScalar::from(3),
+            message
+        ));
+        assert!(!wots_verify(
+            &PARAMS,
+            &public_key,
+            &signature[1..],
+            seed,
+            message
+        ));
+
+        // Malformed signatures and oversized messages are rejected
+        let mut longer = signature.clone();
+        longer.push(BlsScalar::one());
+        assert!(!wots_verify(&PARAMS, &public_key, &longer, seed, message));
+        assert!(!wots_verify(
+            &PARAMS,
+            &public_key,
+            &signature,
+            seed,
+            BlsScalar::from(0x1a7)
+        ));
+        assert!(PARAMS.checked_digits(BlsScalar::from(0x1a7)).is_none());
+
+        // Advancing a chain to increase a message digit breaks the checksum
+        let mut forged = signature.clone();
+        forged[1] = wots_chain(forged[1], 1, 1, 1, seed);
+        assert!(!wots_verify(
+            &PARAMS,
+            &public_key,
+            &forged,
+            seed,
+            BlsScalar::from(0xab)
+        ));
+    }
+
+    #[test]
+    fn test_wots_default_parameters() {
+        let params = WotsParameters::default();
+        let seed = BlsScalar::from(2);
+        let secret_key = wots_secret_key(&params, BlsScalar::from(1), 0);
+        let public_key = wots_public_key(&params, &secret_key, seed);
+        let message = -BlsScalar::one();
+        let signature = wots_sign(&params, &secret_key, seed, message);
+        assert!(wots_verify(&params, &public_key, &signature, seed, message));
+    }
+
+    #[test]
+    fn test_wots_public_key_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let seed_scalar = BlsScalar::from(2);
+                let message_scalar = BlsScalar::from(0xa7);
+                let secret_key =
+                    wots_secret_key(&PARAMS, BlsScalar::from(1), 0);
+                let public_key =
+                    wots_public_key(&PARAMS, &secret_key, seed_scalar);
+                let signature = wots_sign(
+                    &PARAMS,
+                    &secret_key,
+                    seed_scalar,
+                    message_scalar,
+                );
+
+                let seed = composer.add_input(seed_scalar);
+                let message = composer.add_input(message_scalar);
+                let signature_scalars = signature;
+                let signature: Vec<Variable> = signature_scalars
+                    .iter()
+                    .map(|s| composer.add_input(*s))
+                    .collect();
+                let computed = wots_public_key_gadget(
+                    composer,
+                    &constants,
+                    &PARAMS,
+                    seed,
+                    seed_scalar,
+                    message,
+                    message_scalar,
+                    &signature,
+                    &signature_scalars,
+                );
+                let leaf = wots_leaf_gadget(
+                    composer,
+                    &constants,
+                    &computed,
+                    &public_key,
+                );
+                composer.constrain_to_constant(
+                    leaf,
+                    BlsScalar::zero(),
+                    Some(-wots_leaf(&public_key)),
+                );
+
+                pad_lookup_gates(composer);
+            },
+            33000,
+        );
+        assert!(res.is_ok());
+    }
+}
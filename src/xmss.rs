@@ -0,0 +1,380 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Many-time signatures in the style of XMSS, placing `2^height` Winternitz
+//! public keys under a Merkle root.
+//!
+//! The Winternitz secret key with index `i` is derived from the secret seed,
+//! and the leaf `i` of the tree is its compressed public key. A signature is
+//! the index of the key used, the Winternitz signature and the authentication
+//! path of the leaf. The secret key is stateful: every index is used at most
+//! once, and signing fails once all of them are used.
+//!
+//! The chain tweaks depend on the chain and the position only, and the nodes
+//! of the tree are compressed with the same function at every level, so the
+//! hashes are not bound to the index of the key nor to the level in the tree.
+//! An attacker may then invert any of the `2^height · num_chains · (w - 1)`
+//! chain steps of the published keys, or find a second preimage of any of the
+//! `2^(height + 1)` nodes, to forge a signature: the security is reduced by
+//! `height + log2(num_chains · w)` bits compared to a single Winternitz key.
+
+use crate::hashing::gadgets::scalar_bits_gadget;
+use crate::hashing::zelbet::ZelbetConstants;
+use crate::merkle::merkle_compress;
+use crate::merkle::opening::{merkle_path_gadget, merkle_root_out_of};
+use crate::wots::{
+    wots_leaf, wots_leaf_gadget, wots_public_key,
+    wots_public_key_from_signature, wots_public_key_gadget, wots_secret_key,
+    wots_sign, WotsParameters,
+};
+use dusk_plonk::{
+    constraint_system::{StandardComposer, Variable},
+    prelude::BlsScalar,
+};
+
+/// Public key, the root of the tree of Winternitz public keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XmssPublicKey {
+    /// Parameters of the Winternitz keys
+    pub params: WotsParameters,
+    /// Height of the tree
+    pub height: usize,
+    /// Root of the tree
+    pub root: BlsScalar,
+    /// Seed of the hash chains
+    pub public_seed: BlsScalar,
+}
+
+/// Signature, made of a Winternitz signature and the authentication path of
+/// its public key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmssSignature {
+    /// Index of the Winternitz key used
+    pub index: u64,
+    /// Winternitz signature of the message
+    pub wots: Vec<BlsScalar>,
+    /// Siblings of the leaf of the key in the tree
+    pub siblings: Vec<BlsScalar>,
+}
+
+/// Stateful secret key. The nodes of the tree are stored once, level by level
+/// from the leaves, and the authentication paths are read from them.
+#[derive(Debug, Clone)]
+pub struct XmssSecretKey {
+    params: WotsParameters,
+    secret_seed: BlsScalar,
+    public_seed: BlsScalar,
+    levels: Vec<Vec<BlsScalar>>,
+    next: u64,
+}
+
+impl XmssSecretKey {
+    /// Generate the tree of `2^height` keys, which requires computing every
+    /// Winternitz public key and storing the `2^(height + 1) - 1` nodes of the
+    /// tree
+    pub fn new(
+        params: WotsParameters,
+        height: usize,
+        secret_seed: BlsScalar,
+        public_seed: BlsScalar,
+    ) -> Self {
+        assert!(height < 64, "indices are stored as u64");
+        let leaves = (0..1u64 << height)
+            .map(|index| {
+                let secret_key = wots_secret_key(&params, secret_seed, index);
+                let public_key =
+                    wots_public_key(&params, &secret_key, public_seed);
+                wots_leaf(&public_key)
+            })
+            .collect();
+
+        let mut levels: Vec<Vec<BlsScalar>> = vec![leaves];
+        (0..height).for_each(|level| {
+            let parents = levels[level]
+                .chunks(2)
+                .map(|pair| merkle_compress(pair[0], pair[1]))
+                .collect();
+            levels.push(parents);
+        });
+
+        XmssSecretKey {
+            params,
+            secret_seed,
+            public_seed,
+            levels,
+            next: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// Public key of the tree
+    pub fn public_key(&self) -> XmssPublicKey {
+        XmssPublicKey {
+            params: self.params,
+            height: self.height(),
+            root: self.levels[self.height()][0],
+            public_seed: self.public_seed,
+        }
+    }
+
+    /// Number of signatures that can still be made
+    pub fn remaining(&self) -> u64 {
+        (1 << self.height()) - self.next
+    }
+
+    /// Sign a message with the next unused key, or return `None` when all the
+    /// keys are used
+    pub fn sign(&mut self, message: BlsScalar) -> Option<XmssSignature> {
+        let index = self.next;
+        if index >> self.height() != 0 {
+            return None;
+        }
+        self.next += 1;
+
+        // The sibling at every level is the other child of the same parent
+        let siblings = (0..self.height())
+            .map(|level| self.levels[level][((index >> level) ^ 1) as usize])
+            .collect();
+
+        let secret_key = wots_secret_key(&self.params, self.secret_seed, index);
+        Some(XmssSignature {
+            index,
+            wots: wots_sign(
+                &self.params,
+                &secret_key,
+                self.public_seed,
+                message,
+            ),
+            siblings,
+        })
+    }
+}
+
+impl XmssPublicKey {
+    /// Verify the signature of a message
+    pub fn verify(
+        &self,
+        message: BlsScalar,
+        signature: &XmssSignature,
+    ) -> bool {
+        if signature.wots.len() != self.params.num_chains()
+            || signature.siblings.len() != self.height
+            || signature.index >> self.height != 0
+        {
+            return false;
+        }
+
+        let public_key = match wots_public_key_from_signature(
+            &self.params,
+            &signature.wots,
+            self.public_seed,
+            message,
+        ) {
+            Some(public_key) => public_key,
+            None => return false,
+        };
+        let path: Vec<bool> = (0..self.height)
+            .map(|level| (signature.index >> level) & 1 == 1)
+            .collect();
+        merkle_root_out_of(wots_leaf(&public_key), &signature.siblings, &path)
+            == self.root
+    }
+}
+
+/// Verify a signature in circuit, where the root and public seed of the
+/// public key are public inputs and the message is given as a variable along
+/// with its value. The signature is added as a witness.
+pub fn xmss_verification_gadget(
+    composer: &mut StandardComposer,
+    constants: &ZelbetConstants,
+    public_key: &XmssPublicKey,
+    message: Variable,
+    message_scalar: BlsScalar,
+    signature: &XmssSignature,
+) {
+    let public_seed = composer.add_input(public_key.public_seed);
+    composer.constrain_to_constant(
+        public_seed,
+        BlsScalar::zero(),
+        Some(-public_key.public_seed),
+    );
+
+    let wots: Vec<Variable> = signature
+        .wots
+        .iter()
+        .map(|s| composer.add_input(*s))
+        .collect();
+    // A message too wide for the parameters has no public key, and its
+    // signature cannot be proven whatever values are witnessed
+    let wots_public_key_scalars = wots_public_key_from_signature(
+        &public_key.params,
+        &signature.wots,
+        public_key.public_seed,
+        message_scalar,
+    )
+    .unwrap_or_else(|| vec![BlsScalar::zero(); public_key.params.num_chains()]);
+    let wots_public_key = wots_public_key_gadget(
+        composer,
+        constants,
+        &public_key.params,
+        public_seed,
+        public_key.public_seed,
+        message,
+        message_scalar,
+        &wots,
+        &signature.wots,
+    );
+    let leaf = wots_leaf_gadget(
+        composer,
+        constants,
+        &wots_public_key,
+        &wots_public_key_scalars,
+    );
+
+    let index_scalar = BlsScalar::from(signature.index);
+    let index = composer.add_input(index_scalar);
+    let path =
+        scalar_bits_gadget(composer, index, index_scalar, public_key.height);
+    let path_values: Vec<bool> = (0..public_key.height)
+        .map(|level| (signature.index >> level) & 1 == 1)
+        .collect();
+    let siblings: Vec<Variable> = signature
+        .siblings
+        .iter()
+        .map(|s| composer.add_input(*s))
+        .collect();
+    let root = merkle_path_gadget(
+        composer,
+        constants,
+        leaf,
+        wots_leaf(&wots_public_key_scalars),
+        &siblings,
+        &signature.siblings,
+        &path,
+        &path_values,
+    );
+    composer.constrain_to_constant(
+        root,
+        BlsScalar::zero(),
+        Some(-public_key.root),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadget_tester;
+    use crate::hashing::gadgets::{bar_lookup_table, pad_lookup_gates};
+
+    const PARAMS: WotsParameters = WotsParameters {
+        log_w: 2,
+        message_bits: 8,
+    };
+
+    fn test_key() -> XmssSecretKey {
+        XmssSecretKey::new(PARAMS, 2, BlsScalar::from(1), BlsScalar::from(2))
+    }
+
+    #[test]
+    fn test_xmss_vectors() {
+        assert_eq!(
+            test_key().public_key().root,
+            BlsScalar([
+                6829125185000982153,
+                2027907818151428074,
+                17215109493393939515,
+                7006069609035466539,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_xmss_sign_and_verify() {
+        let mut secret_key = test_key();
+        let public_key = secret_key.public_key();
+
+        (0..4u64).for_each(|index| {
+            assert_eq!(secret_key.remaining(), 4 - index);
+            let message = BlsScalar::from(0x10 + index);
+            let signature = secret_key.sign(message).unwrap();
+            assert_eq!(signature.index, index);
+            assert!(public_key.verify(message, &signature));
+            assert!(!public_key.verify(BlsScalar::from(0xff), &signature));
+            // Messages wider than the parameters are rejected
+            assert!(!public_key.verify(BlsScalar::from(0x100), &signature));
+
+            // The signature is bound to the position of its key
+            let mut moved = signature.clone();
+            moved.index ^= 1;
+            assert!(!public_key.verify(message, &moved));
+        });
+
+        assert_eq!(secret_key.remaining(), 0);
+        assert!(secret_key.sign(BlsScalar::one()).is_none());
+    }
+
+    #[test]
+    fn test_xmss_verification_gadget() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let mut secret_key = test_key();
+                secret_key.sign(BlsScalar::from(0x10));
+                let message_scalar = BlsScalar::from(0xa7);
+                let signature = secret_key.sign(message_scalar).unwrap();
+
+                let message = composer.add_input(message_scalar);
+                xmss_verification_gadget(
+                    composer,
+                    &constants,
+                    &secret_key.public_key(),
+                    message,
+                    message_scalar,
+                    &signature,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            33000,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_xmss_verification_gadget_wrong_message_fails() {
+        let res = gadget_tester(
+            |composer| {
+                let hash_table = bar_lookup_table();
+                composer.append_lookup_table(&hash_table);
+                let constants = ZelbetConstants::new(composer);
+
+                let mut secret_key = test_key();
+                let signature = secret_key.sign(BlsScalar::from(0xa7)).unwrap();
+
+                let message_scalar = BlsScalar::from(0xa6);
+                let message = composer.add_input(message_scalar);
+                xmss_verification_gadget(
+                    composer,
+                    &constants,
+                    &secret_key.public_key(),
+                    message,
+                    message_scalar,
+                    &signature,
+                );
+
+                pad_lookup_gates(composer);
+            },
+            33000,
+        );
+        assert!(res.is_err());
+    }
+}